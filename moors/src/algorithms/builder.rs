@@ -23,7 +23,23 @@
 //! 3. Call `.build()?` to validate parameters and obtain a `GeneticAlgorithm<S,Sel,Sur,Cross,Mut,F,G,DC>`.
 //! 4. Call `.run()?`. Internally, this will initialize the population, then loop
 //!    through the requested number of iterations, evolving, evaluating, and selecting
-//!    survivors. If `verbose` is enabled, it prints out per‑iteration minima.
+//!    survivors (with `num_iterations == 0` only the evaluated initial population is
+//!    produced). If `verbose` is enabled, it prints out per‑iteration minima. If
+//!    `diversity_pressure` is positive (single-objective only), the merged population is
+//!    first truncated to `population_size` greedily trading fitness for distance in gene
+//!    space, and the survival operator only ranks the truncated population. If
+//!    `rolling_stats` is enabled, constant-memory [`RollingStats`] (best-so-far per
+//!    objective, cumulative evaluations, on-demand hypervolume estimate) are updated after
+//!    every generation; past generations are never stored.
+//!
+//! Instead of `.run()`, the generations can also be driven with `ask`/`tell`, evaluating the
+//! offspring outside of *moors*; `pipeline_depth` batches can be in flight at once.
+//!
+//! With the `result-cache` feature, `.result_cache(dir, problem_key)` memoizes `.run()` on disk:
+//! the final population is stored under the [`config_hash`](GeneticAlgorithm::config_hash) of
+//! the configuration and an identical rerun restores it without evaluating anything. The
+//! fitness and constraints functions cannot be hashed, so `problem_key` must change whenever
+//! they do.
 //!
 //! ## Key types
 //! - **`AlgorithmBuilder<...>`** – builder type generated via `derive_builder`; use
@@ -31,19 +47,31 @@
 //! - **`GeneticAlgorithm<...>`** – the engine; once constructed, call `.run()` to
 //!   execute the optimization loop.
 
-use std::marker::PhantomData;
+use std::{fmt, marker::PhantomData, sync::Arc};
+#[cfg(feature = "result-cache")]
+use std::{fmt::Debug, path::PathBuf};
 
 use derive_builder::Builder;
-use ndarray::{Axis, concatenate};
+use ndarray::{Array1, Array2, ArrayBase, Axis, Dimension, OwnedRepr, concatenate};
 
+#[cfg(feature = "result-cache")]
+use crate::algorithms::helpers::{
+    OptimizationResult,
+    result_cache::{self, ResultCacheConfig},
+};
 use crate::{
     algorithms::helpers::{
-        AlgorithmContext, AlgorithmContextBuilder, AlgorithmError,
+        AlgorithmContext, AlgorithmContextBuilder, AlgorithmError, AskTellError,
+        InitializationError, RollingStats,
+        diversity::diversity_truncation,
         initialization::Initialization,
         validators::{validate_bounds, validate_positive, validate_probability},
     },
     duplicates::{NoDuplicatesCleaner, PopulationCleaner},
-    evaluator::{ConstraintsFn, Evaluator, EvaluatorBuilder, FitnessFn, NoConstraints},
+    evaluator::{
+        ConstraintsFn, Evaluator, EvaluatorBuilder, EvaluatorError, FitnessFn, NoConstraints,
+        validate_weight,
+    },
     genetic::Population,
     helpers::printer::algorithm_printer,
     operators::{
@@ -89,6 +117,8 @@ pub struct GeneticAlgorithmParams<
     num_vars: usize,
     population_size: usize,
     num_offsprings: usize,
+    /// Number of generations. `0` is allowed: `run` then only samples, evaluates and ranks
+    /// the initial population.
     num_iterations: usize,
     #[builder(default = "0.2")]
     mutation_rate: f64,
@@ -98,8 +128,40 @@ pub struct GeneticAlgorithmParams<
     keep_infeasible: bool,
     #[builder(default = "false")]
     verbose: bool,
+    /// Weight in `[0, 1]` of gene-space diversity, used as an implicit second objective when
+    /// truncating single-objective populations. `0.0` (the default) disables it.
+    #[builder(default = "0.0")]
+    diversity_pressure: f64,
+    /// Objective values differing by at most this amount are treated as equal when the survivor
+    /// checks Pareto dominance, so float noise does not split nearly identical points into
+    /// different fronts. When unset the survivor uses its own (strict) dominance.
+    #[builder(setter(strip_option), default = "None")]
+    dominance_epsilon: Option<f64>,
+    /// Computes the weight of each new individual from its genes (one row per individual). The
+    /// weights only break survival ties, see [`Population::weight`]. Individuals keep their
+    /// weight across generations; without a weight function new individuals get `1.0`. Every
+    /// weight must be strictly positive, otherwise the evaluation fails with
+    /// [`EvaluatorError::InvalidWeights`](crate::evaluator::EvaluatorError::InvalidWeights).
+    ///
+    /// Only the survivors built on
+    /// [`FrontsAndRankingBasedSurvival`](crate::operators::FrontsAndRankingBasedSurvival)
+    /// (NSGA-II, RNSGA-II and AGE-MOEA) use the weights; NSGA-III, REVEA, SPEA2, IBEA and the
+    /// single-objective survivors ignore them.
+    #[builder(setter(custom), default = "None")]
+    weight_fn: Option<WeightFn>,
+    /// Maximum number of offspring batches handed out by `ask` and not yet told back.
+    #[builder(default = "1")]
+    pipeline_depth: usize,
+    /// Keep constant-memory [`RollingStats`] updated after every generation.
+    #[builder(default = "false")]
+    rolling_stats: bool,
     #[builder(setter(strip_option), default = "None")]
     seed: Option<u64>,
+    #[cfg(feature = "result-cache")]
+    #[allow(clippy::type_complexity)]
+    #[builder(setter(custom), default = "None")]
+    result_cache:
+        Option<ResultCacheConfig<GeneticAlgorithmParams<S, Sel, Sur, Cross, Mut, F, G, DC>>>,
 }
 
 impl<S, Sel, Sur, Cross, Mut, F, G, DC> AlgorithmBuilder<S, Sel, Sur, Cross, Mut, F, G, DC>
@@ -127,11 +189,41 @@ where
         if let Some(mutation_rate) = self.mutation_rate {
             validate_probability(mutation_rate, "Mutation rate")?;
         }
+        if let Some(diversity_pressure) = self.diversity_pressure {
+            validate_probability(diversity_pressure, "Diversity pressure")?;
+            if diversity_pressure > 0.0 && F::Dim::NDIM != Some(1) {
+                return Err(AlgorithmBuilderError::ValidationError(
+                    "Diversity pressure is only supported for single-objective optimization"
+                        .to_string(),
+                ));
+            }
+        }
+        if let Some(Some(dominance_epsilon)) = self.dominance_epsilon
+            && !(dominance_epsilon >= 0.0 && dominance_epsilon.is_finite())
+        {
+            return Err(AlgorithmBuilderError::ValidationError(format!(
+                "Dominance epsilon must be a non-negative finite number, got {dominance_epsilon}"
+            )));
+        }
         if let Some(num_offsprings) = self.num_offsprings {
             validate_positive(num_offsprings, "Number of offsprings")?;
         }
-        if let Some(num_iterations) = self.num_iterations {
-            validate_positive(num_iterations, "Number of iterations")?;
+        if let Some(pipeline_depth) = self.pipeline_depth {
+            validate_positive(pipeline_depth, "Pipeline depth")?;
+        }
+        #[cfg(feature = "result-cache")]
+        if let Some(Some(cache)) = &self.result_cache {
+            if !matches!(self.seed, Some(Some(_))) {
+                return Err(AlgorithmBuilderError::ValidationError(
+                    "The result cache requires a seed, unseeded runs are not reproducible"
+                        .to_string(),
+                ));
+            }
+            if cache.problem_key().trim().is_empty() {
+                return Err(AlgorithmBuilderError::ValidationError(
+                    "The result cache requires a non-empty problem key".to_string(),
+                ));
+            }
         }
         if let Some(cf) = &self.constraints_fn {
             // Now call the trait methods (note the parentheses!)
@@ -142,11 +234,30 @@ where
         Ok(())
     }
 
+    /// Sets the function computing the weight of each new individual (see the `weight_fn`
+    /// parameter). Like the fitness function it may capture state; it must be `Send + Sync` so
+    /// the algorithm can still be moved across threads.
+    pub fn weight_fn<W>(mut self, weight_fn: W) -> Self
+    where
+        W: Fn(&Array2<f64>) -> Array1<f64> + Send + Sync + 'static,
+    {
+        self.weight_fn = Some(Some(WeightFn(Arc::new(weight_fn))));
+        self
+    }
+
     #[allow(clippy::type_complexity)]
     pub fn build(
         self,
     ) -> Result<GeneticAlgorithm<S, Sel, Sur, Cross, Mut, F, G, DC>, AlgorithmBuilderError> {
         let params = self.build_params()?;
+        #[cfg(feature = "result-cache")]
+        let (result_cache_dir, config_hash) = match &params.result_cache {
+            Some(cache) => (
+                Some(cache.dir().to_path_buf()),
+                Some(cache.config_hash(&params)),
+            ),
+            None => (None, None),
+        };
         let lb = params.constraints_fn.lower_bound();
         let ub = params.constraints_fn.upper_bound();
 
@@ -188,12 +299,44 @@ where
             evaluator,
             context,
             verbose: params.verbose,
+            diversity_pressure: params.diversity_pressure,
+            dominance_epsilon: params.dominance_epsilon,
+            weight_fn: params.weight_fn,
+            rolling_stats: params.rolling_stats.then(RollingStats::default),
+            pipeline_depth: params.pipeline_depth,
+            in_flight: Vec::new(),
+            next_ticket: 0,
+            num_told: 0,
+            #[cfg(feature = "result-cache")]
+            result_cache_dir,
+            #[cfg(feature = "result-cache")]
+            config_hash,
+            #[cfg(feature = "result-cache")]
+            restored_evaluations: 0,
             rng,
             phantom: PhantomData,
         })
     }
 }
 
+/// Computes one weight per row of genes, see the builder's `weight_fn`.
+#[derive(Clone)]
+struct WeightFn(Arc<WeightCallback>);
+
+type WeightCallback = dyn Fn(&Array2<f64>) -> Array1<f64> + Send + Sync;
+
+impl WeightFn {
+    fn call(&self, genes: &Array2<f64>) -> Array1<f64> {
+        (self.0)(genes)
+    }
+}
+
+impl fmt::Debug for WeightFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WeightFn")
+    }
+}
+
 #[derive(Debug)]
 pub struct GeneticAlgorithm<S, Sel, Sur, Cross, Mut, F, G, DC>
 where
@@ -213,10 +356,34 @@ where
     evaluator: Evaluator<F, G>,
     pub context: AlgorithmContext,
     verbose: bool,
+    diversity_pressure: f64,
+    dominance_epsilon: Option<f64>,
+    weight_fn: Option<WeightFn>,
+    rolling_stats: Option<RollingStats>,
+    pipeline_depth: usize,
+    in_flight: Vec<AskedOffspring>,
+    next_ticket: usize,
+    /// Number of batches told back so far.
+    num_told: usize,
+    #[cfg(feature = "result-cache")]
+    result_cache_dir: Option<PathBuf>,
+    #[cfg(feature = "result-cache")]
+    config_hash: Option<u64>,
+    /// Evaluations performed by the run restored from the result cache.
+    #[cfg(feature = "result-cache")]
+    restored_evaluations: usize,
     rng: MOORandomGenerator,
     phantom: PhantomData<S>,
 }
 
+/// A batch of offspring handed out by [`GeneticAlgorithm::ask`], to be evaluated externally and
+/// told back with [`GeneticAlgorithm::tell`] using its `ticket`.
+#[derive(Debug, Clone)]
+pub struct AskedOffspring {
+    pub ticket: usize,
+    pub genes: Array2<f64>,
+}
+
 impl<S, Sel, Sur, Cross, Mut, F, G, DC> GeneticAlgorithm<S, Sel, Sur, Cross, Mut, F, G, DC>
 where
     S: SamplingOperator,
@@ -248,52 +415,269 @@ where
         // Combine the current population with the offspring.
         let combined_genes = concatenate(Axis(0), &[ref_pop.genes.view(), offspring_genes.view()])
             .expect("Failed to concatenate current population genes with offspring genes");
+        let combined_weight = self.combined_weight(ref_pop, &offspring_genes);
         // Evaluate the fitness and constraints and create Population
-        let evaluated_population = self.evaluator.evaluate(combined_genes)?;
+        let mut evaluated_population = self
+            .evaluator
+            .evaluate_weighted(combined_genes, combined_weight)?;
+        // Truncate keeping gene-space diversity, the survivor only ranks the remaining ones
+        if self.diversity_pressure > 0.0 {
+            evaluated_population = diversity_truncation(
+                evaluated_population,
+                self.context.population_size,
+                self.diversity_pressure,
+            );
+        }
 
         // Select survivors to the next iteration population
-        let survivors = self.survivor.operate(
-            evaluated_population,
-            self.context.population_size,
-            &mut self.rng,
-        );
+        let survivors = self.survive(evaluated_population);
         // Update the population attribute
         self.population = Some(survivors);
+        self.update_rolling_stats(true);
+
+        Ok(())
+    }
+
+    /// Creates a batch of offspring from the current population without evaluating it.
+    ///
+    /// Up to `pipeline_depth` batches can be in flight at the same time, so remote evaluators can
+    /// be kept busy: while a batch is being evaluated, the next ones can already be asked for.
+    /// The trade-off is **staleness**: every batch is bred from the population as it is when
+    /// `ask` is called, so batches asked before the previous ones are told back derive from an
+    /// older population (by up to `pipeline_depth - 1` generations). With `pipeline_depth = 1`
+    /// every batch is bred from the latest population, as in [`Self::next_pop`]. Unlike
+    /// `next_pop`, `tell` only evaluates the offspring: the current population is neither
+    /// re-evaluated nor re-filtered for feasibility, so the evaluation counts differ.
+    ///
+    /// The population must be initialized (see [`Self::initialize`]).
+    pub fn ask(&mut self) -> Result<AskedOffspring, AlgorithmError> {
+        if self.in_flight.len() >= self.pipeline_depth {
+            return Err(AskTellError::PipelineFull(self.in_flight.len()).into());
+        }
+        let ref_pop = self
+            .population
+            .as_ref()
+            .ok_or_else(|| InitializationError::NotInitializated("population is not set".into()))?;
+        let genes = self
+            .evolve
+            .evolve(ref_pop, self.context.num_offsprings, 200, &mut self.rng)
+            .map_err::<AlgorithmError, _>(Into::into)?;
+        let asked = AskedOffspring {
+            ticket: self.next_ticket,
+            genes,
+        };
+        self.next_ticket += 1;
+        self.in_flight.push(asked.clone());
+        Ok(asked)
+    }
 
+    /// Tells back the fitness of an asked batch, in any order, and runs the survival step on the
+    /// current population merged with the batch. The constraints of the batch are computed
+    /// locally with the constraints function. The current population is not re-evaluated.
+    /// Offspring duplicating individuals that joined the population since the batch was asked
+    /// are discarded, and so are infeasible ones when infeasible individuals are not kept. If no
+    /// offspring is left, the survival step runs on the current population alone. Any other
+    /// evaluator error (e.g. invalid weights) is returned and the batch is dropped.
+    ///
+    /// Every successful call completes one generation and advances `context.current_iteration`
+    /// like `run` does.
+    pub fn tell(
+        &mut self,
+        ticket: usize,
+        fitness: ArrayBase<OwnedRepr<f64>, F::Dim>,
+    ) -> Result<(), AlgorithmError> {
+        let position = self
+            .in_flight
+            .iter()
+            .position(|asked| asked.ticket == ticket)
+            .ok_or(AskTellError::UnknownTicket(ticket))?;
+        let expected = self.in_flight[position].genes.nrows();
+        if fitness.len_of(Axis(0)) != expected {
+            return Err(AskTellError::FitnessMismatch {
+                expected,
+                found: fitness.len_of(Axis(0)),
+            }
+            .into());
+        }
+        let asked = self.in_flight.remove(position);
+        let current = self
+            .population
+            .as_ref()
+            .ok_or_else(|| InitializationError::NotInitializated("population is not set".into()))?;
+        // The population may have changed since the batch was asked: drop the offspring that
+        // duplicate the current individuals.
+        let kept: Vec<usize> = (0..asked.genes.nrows())
+            .filter(|&i| {
+                let row = asked.genes.row(i).insert_axis(Axis(0)).to_owned();
+                self.evolve
+                    .duplicates_cleaner
+                    .remove(row, Some(&current.genes))
+                    .nrows()
+                    > 0
+            })
+            .collect();
+        let offspring = if kept.is_empty() {
+            None
+        } else {
+            let genes = asked.genes.select(Axis(0), &kept);
+            let weight = self
+                .weight_fn
+                .as_ref()
+                .map(|weight_fn| weight_fn.call(&genes));
+            match self.evaluator.evaluate_with_fitness_weighted(
+                genes,
+                fitness.select(Axis(0), &kept),
+                weight,
+            ) {
+                Ok(offspring) => Some(offspring),
+                // A batch without feasible individuals (when infeasible ones are not kept)
+                // simply brings no new offspring.
+                Err(EvaluatorError::NoFeasibleIndividuals) => None,
+                Err(err) => return Err(err.into()),
+            }
+        };
+        let mut combined = match offspring {
+            Some(offspring) => {
+                let genes = concatenate(Axis(0), &[current.genes.view(), offspring.genes.view()])
+                    .expect("Failed to concatenate current population and offspring genes");
+                let fitness =
+                    concatenate(Axis(0), &[current.fitness.view(), offspring.fitness.view()])
+                        .expect("Failed to concatenate current population and offspring fitness");
+                let constraints = concatenate(
+                    Axis(0),
+                    &[current.constraints.view(), offspring.constraints.view()],
+                )
+                .expect("Failed to concatenate current population and offspring constraints");
+                let mut combined = Population::new(genes, fitness, constraints);
+                if current.weight.is_some() || offspring.weight.is_some() {
+                    let weight = concatenate(
+                        Axis(0),
+                        &[current.weights().view(), offspring.weights().view()],
+                    )
+                    .expect("Failed to concatenate current population and offspring weights");
+                    combined.set_weight(weight);
+                }
+                combined
+            }
+            None => current.clone(),
+        };
+        if self.diversity_pressure > 0.0 {
+            combined = diversity_truncation(
+                combined,
+                self.context.population_size,
+                self.diversity_pressure,
+            );
+        }
+        let survivors = self.survive(combined);
+        self.population = Some(survivors);
+        self.update_rolling_stats(true);
+        // Like `run`, record the (zero-based) generation just completed so iteration-dependent
+        // operators see the run progress.
+        self.context.set_current_iteration(self.num_told);
+        self.num_told += 1;
         Ok(())
     }
 
+    /// Number of asked batches not yet told back.
+    pub fn num_in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+
     pub fn initialize(&mut self) -> Result<(), AlgorithmError> {
         // Create the first Population
-        let initial_population = Initialization::initialize(
-            &self.sampler,
-            &mut self.survivor,
-            &mut self.evaluator,
-            &self.evolve.duplicates_cleaner,
-            &mut self.rng,
-            &self.context,
-        )?;
+        let mut initial_population = match self.dominance_epsilon {
+            Some(dominance_epsilon) => Initialization::initialize_with_dominance_epsilon(
+                &self.sampler,
+                &mut self.survivor,
+                &mut self.evaluator,
+                &self.evolve.duplicates_cleaner,
+                dominance_epsilon,
+                &mut self.rng,
+                &self.context,
+            )?,
+            None => Initialization::initialize(
+                &self.sampler,
+                &mut self.survivor,
+                &mut self.evaluator,
+                &self.evolve.duplicates_cleaner,
+                &mut self.rng,
+                &self.context,
+            )?,
+        };
+        if let Some(weight_fn) = &self.weight_fn {
+            let weight = weight_fn.call(&initial_population.genes);
+            validate_weight(&weight, initial_population.len())?;
+            initial_population.set_weight(weight);
+        }
         // Update population attribute
         self.population = Some(initial_population);
+        self.update_rolling_stats(false);
         Ok(())
     }
 
+    /// Running statistics of the run, `None` unless the algorithm was built with
+    /// `.rolling_stats(true)`. Only generations computed by this instance are recorded, so
+    /// they stay empty after `run` restores a result from the result cache.
+    pub fn rolling_stats(&self) -> Option<&RollingStats> {
+        self.rolling_stats.as_ref()
+    }
+
+    /// Weights of `parents` followed by the weights of the offspring genes, `None` if nobody
+    /// carries weights.
+    fn combined_weight(
+        &self,
+        parents: &Population<F::Dim, G::Dim>,
+        offspring_genes: &Array2<f64>,
+    ) -> Option<Array1<f64>> {
+        if parents.weight.is_none() && self.weight_fn.is_none() {
+            return None;
+        }
+        let offspring_weight = match &self.weight_fn {
+            Some(weight_fn) => weight_fn.call(offspring_genes),
+            None => Array1::ones(offspring_genes.nrows()),
+        };
+        Some(
+            concatenate(
+                Axis(0),
+                &[parents.weights().view(), offspring_weight.view()],
+            )
+            .expect("Failed to concatenate current population weights with offspring weights"),
+        )
+    }
+
+    /// Runs the survivor on `population`, with the builder's `dominance_epsilon` if it was set.
+    fn survive(&mut self, population: Population<F::Dim, G::Dim>) -> Population<F::Dim, G::Dim> {
+        let num_survive = self.context.population_size;
+        match self.dominance_epsilon {
+            Some(dominance_epsilon) => self.survivor.operate_with_dominance_epsilon(
+                population,
+                num_survive,
+                dominance_epsilon,
+                &mut self.rng,
+            ),
+            None => self
+                .survivor
+                .operate(population, num_survive, &mut self.rng),
+        }
+    }
+
+    fn update_rolling_stats(&mut self, is_generation: bool) {
+        if let (Some(stats), Some(population)) = (&mut self.rolling_stats, &self.population) {
+            stats.update(population, self.evaluator.num_evaluations(), is_generation);
+        }
+    }
+
     pub fn set_current_iteration(&mut self, current_iter: usize) {
         self.context.set_current_iteration(current_iter);
     }
 
     pub fn run(&mut self) -> Result<(), AlgorithmError> {
+        #[cfg(feature = "result-cache")]
+        if self.restore_cached_result()? {
+            return Ok(());
+        }
         // Create the first Population
-        let initial_population = Initialization::initialize(
-            &self.sampler,
-            &mut self.survivor,
-            &mut self.evaluator,
-            &self.evolve.duplicates_cleaner,
-            &mut self.rng,
-            &self.context,
-        )?;
-        // Update population attribute
-        self.population = Some(initial_population);
+        self.initialize()?;
 
         for current_iter in 0..self.context.num_iterations {
             match self.next_pop() {
@@ -313,6 +697,151 @@ where
             }
             self.context.set_current_iteration(current_iter);
         }
+        #[cfg(feature = "result-cache")]
+        self.store_result()?;
         Ok(())
     }
+
+    /// Hash of the configuration keying the result cache, `None` if the cache is disabled.
+    ///
+    /// It covers the crate version, the `problem_key` given to `.result_cache`, the operators
+    /// (through their `Debug` representation), the constraint bounds, the numeric parameters
+    /// and the seed.
+    ///
+    /// **The fitness, constraints and weight functions are not part of the hash.** Their code
+    /// and any state captured by closures cannot be inspected, so two different problems solved
+    /// with the same `problem_key` and parameters share a cache entry and the second one
+    /// silently gets the result of the first. Change `problem_key` (e.g. add a version suffix)
+    /// whenever the problem definition changes.
+    #[cfg(feature = "result-cache")]
+    pub fn config_hash(&self) -> Option<u64> {
+        self.config_hash
+    }
+
+    /// Snapshot of the current population and the number of evaluations it took, `None` before
+    /// the population is initialized.
+    #[cfg(feature = "result-cache")]
+    pub fn optimization_result(&self) -> Option<OptimizationResult<F::Dim, G::Dim>> {
+        self.population
+            .as_ref()
+            .map(|population| OptimizationResult {
+                population: population.clone(),
+                num_evaluations: self.restored_evaluations + self.evaluator.num_evaluations(),
+            })
+    }
+
+    /// Restores the population from the result cache, returns whether it was a hit.
+    #[cfg(feature = "result-cache")]
+    fn restore_cached_result(&mut self) -> Result<bool, AlgorithmError> {
+        let (Some(dir), Some(config_hash)) = (&self.result_cache_dir, self.config_hash) else {
+            return Ok(false);
+        };
+        match result_cache::load(&result_cache::cache_file(dir, config_hash))? {
+            Some(result) => {
+                self.population = Some(result.population);
+                self.restored_evaluations = result.num_evaluations;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    #[cfg(feature = "result-cache")]
+    fn store_result(&self) -> Result<(), AlgorithmError> {
+        if let (Some(dir), Some(config_hash), Some(result)) = (
+            &self.result_cache_dir,
+            self.config_hash,
+            self.optimization_result(),
+        ) {
+            result_cache::store(&result_cache::cache_file(dir, config_hash), &result)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "result-cache")]
+impl<S, Sel, Sur, Cross, Mut, F, G, DC> AlgorithmBuilder<S, Sel, Sur, Cross, Mut, F, G, DC>
+where
+    S: SamplingOperator,
+    Sel: SelectionOperator<FDim = F::Dim>,
+    Sur: SurvivalOperator<FDim = F::Dim>,
+    Cross: CrossoverOperator,
+    Mut: MutationOperator,
+    F: FitnessFn,
+    G: ConstraintsFn,
+    DC: PopulationCleaner,
+    S: Debug,
+    Sel: Debug,
+    Sur: Debug,
+    Cross: Debug,
+    Mut: Debug,
+    DC: Debug,
+{
+    /// Memoizes `run` in the directory `dir`. Requires a `seed`.
+    ///
+    /// `problem_key` identifies the problem being solved and **must be changed whenever the
+    /// fitness, constraints or weight functions change**, since their code is not part of
+    /// [`GeneticAlgorithm::config_hash`]. Cache files carry the crate version and are ignored
+    /// by other versions. A cache hit only restores the final population:
+    /// [`GeneticAlgorithm::rolling_stats`] stays empty.
+    pub fn result_cache<P: Into<PathBuf>, K: Into<String>>(
+        mut self,
+        dir: P,
+        problem_key: K,
+    ) -> Self {
+        self.result_cache = Some(Some(ResultCacheConfig::new(
+            dir.into(),
+            problem_key.into(),
+            describe_params,
+        )));
+        self
+    }
+}
+
+/// Canonical description of the parameters that determine the result of `run`.
+#[cfg(feature = "result-cache")]
+fn describe_params<S, Sel, Sur, Cross, Mut, F, G, DC>(
+    params: &GeneticAlgorithmParams<S, Sel, Sur, Cross, Mut, F, G, DC>,
+) -> String
+where
+    S: SamplingOperator,
+    Sel: SelectionOperator<FDim = F::Dim>,
+    Sur: SurvivalOperator<FDim = F::Dim>,
+    Cross: CrossoverOperator,
+    Mut: MutationOperator,
+    F: FitnessFn,
+    G: ConstraintsFn,
+    DC: PopulationCleaner,
+    S: Debug,
+    Sel: Debug,
+    Sur: Debug,
+    Cross: Debug,
+    Mut: Debug,
+    DC: Debug,
+{
+    format!(
+        "sampler={:?}\nselector={:?}\nsurvivor={:?}\ncrossover={:?}\nmutation={:?}\n\
+         duplicates_cleaner={:?}\nconstraint_bounds=[{:?}, {:?}]\n\
+         num_vars={}\npopulation_size={}\nnum_offsprings={}\nnum_iterations={}\n\
+         mutation_rate={:?}\ncrossover_rate={:?}\nkeep_infeasible={}\n\
+         diversity_pressure={:?}\ndominance_epsilon={:?}\nseed={:?}",
+        params.sampler,
+        params.selector,
+        params.survivor,
+        params.crossover,
+        params.mutation,
+        params.duplicates_cleaner,
+        params.constraints_fn.lower_bound(),
+        params.constraints_fn.upper_bound(),
+        params.num_vars,
+        params.population_size,
+        params.num_offsprings,
+        params.num_iterations,
+        params.mutation_rate,
+        params.crossover_rate,
+        params.keep_infeasible,
+        params.diversity_pressure,
+        params.dominance_epsilon,
+        params.seed,
+    )
 }
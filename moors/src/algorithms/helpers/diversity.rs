@@ -0,0 +1,141 @@
+use std::cmp::Ordering;
+
+use ndarray::{Array1, Axis, Ix1};
+
+use crate::genetic::{D12, Population};
+
+/// Truncates a single-objective population to `num_survive` individuals treating gene-space
+/// diversity as an implicit second objective.
+///
+/// Individuals are picked greedily. The first pick is the one with the best fitness; each
+/// following pick minimizes
+///
+/// ```text
+/// (1 − pressure) × normalized_fitness − pressure × normalized_distance
+/// ```
+///
+/// where `normalized_distance` is the distance in the (min-max normalized) gene space to the
+/// closest individual already picked, divided by the largest such distance among the remaining
+/// candidates. Feasible individuals are always picked before infeasible ones; when there are not
+/// enough feasible individuals, the infeasible ones with the least violation fill the gap.
+pub(in crate::algorithms) fn diversity_truncation<FDim, ConstrDim>(
+    population: Population<FDim, ConstrDim>,
+    num_survive: usize,
+    pressure: f64,
+) -> Population<FDim, ConstrDim>
+where
+    FDim: D12,
+    ConstrDim: D12,
+{
+    if population.len() <= num_survive {
+        return population;
+    }
+    let fitness = population
+        .fitness
+        .view()
+        .into_dimensionality::<Ix1>()
+        .expect("Diversity pressure requires a single-objective fitness")
+        .to_owned();
+
+    let mut feasible: Vec<usize> = Vec::new();
+    let mut infeasible: Vec<usize> = Vec::new();
+    for i in 0..population.len() {
+        match &population.constraint_violation_totals {
+            Some(cv) if cv[i] > 0.0 => infeasible.push(i),
+            _ => feasible.push(i),
+        }
+    }
+    if feasible.len() < num_survive {
+        let cv = population
+            .constraint_violation_totals
+            .as_ref()
+            .expect("Infeasible individuals have a constraint violation");
+        infeasible.sort_by(|&i, &j| cv[i].partial_cmp(&cv[j]).unwrap_or(Ordering::Equal));
+        feasible.extend_from_slice(&infeasible[..num_survive - feasible.len()]);
+        return population.selected(&feasible);
+    }
+
+    // Normalize fitness and genes of the candidates to [0, 1].
+    let fitness = fitness.select(Axis(0), &feasible);
+    let genes = population.genes.select(Axis(0), &feasible);
+    let f_min = fitness.fold(f64::INFINITY, |a, &b| a.min(b));
+    let f_max = fitness.fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+    let f_range = if f_max > f_min { f_max - f_min } else { 1.0 };
+    let normalized_fitness = fitness.mapv(|f| (f - f_min) / f_range);
+    let g_min = genes.fold_axis(Axis(0), f64::INFINITY, |&a, &b| a.min(b));
+    let g_max = genes.fold_axis(Axis(0), f64::NEG_INFINITY, |&a, &b| a.max(b));
+    let g_range = (&g_max - &g_min).mapv(|r| if r > 0.0 { r } else { 1.0 });
+    let normalized_genes = (&genes - &g_min) / &g_range;
+
+    let num_candidates = feasible.len();
+    let mut picked = vec![false; num_candidates];
+    let mut min_distances = Array1::from_elem(num_candidates, f64::INFINITY);
+    let mut selected = Vec::with_capacity(num_survive);
+
+    let mut current = (0..num_candidates)
+        .min_by(|&i, &j| normalized_fitness[i].total_cmp(&normalized_fitness[j]))
+        .expect("At least one candidate");
+    loop {
+        picked[current] = true;
+        selected.push(feasible[current]);
+        if selected.len() == num_survive {
+            break;
+        }
+        let current_genes = normalized_genes.row(current);
+        for i in (0..num_candidates).filter(|&i| !picked[i]) {
+            let distance = (&normalized_genes.row(i) - &current_genes)
+                .mapv(|v| v * v)
+                .sum()
+                .sqrt();
+            min_distances[i] = min_distances[i].min(distance);
+        }
+        let max_distance = (0..num_candidates)
+            .filter(|&i| !picked[i])
+            .map(|i| min_distances[i])
+            .fold(0.0, f64::max);
+        let max_distance = if max_distance > 0.0 {
+            max_distance
+        } else {
+            1.0
+        };
+        let score = |i: usize| {
+            (1.0 - pressure) * normalized_fitness[i] - pressure * min_distances[i] / max_distance
+        };
+        current = (0..num_candidates)
+            .filter(|&i| !picked[i])
+            .min_by(|&i, &j| score(i).total_cmp(&score(j)))
+            .expect("There are candidates left");
+    }
+    population.selected(&selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::PopulationSOO;
+    use ndarray::array;
+
+    #[test]
+    fn test_diversity_truncation_spreads_survivors() {
+        // Three clustered good individuals and one far away, slightly worse.
+        let genes = array![[0.0], [0.01], [0.02], [1.0]];
+        let fitness = array![0.0, 0.01, 0.02, 0.1];
+        let population = PopulationSOO::new_unconstrained(genes, fitness);
+
+        let survivors = diversity_truncation(population.clone(), 2, 0.0);
+        assert_eq!(survivors.fitness, array![0.0, 0.01]);
+
+        let survivors = diversity_truncation(population, 2, 0.5);
+        assert_eq!(survivors.fitness, array![0.0, 0.1]);
+    }
+
+    #[test]
+    fn test_diversity_truncation_prefers_feasible() {
+        let genes = array![[0.0], [0.5], [1.0]];
+        let fitness = array![0.0, 1.0, 2.0];
+        let constraints = array![1.0, 0.0, 2.0];
+        let population = PopulationSOO::new(genes, fitness, constraints);
+        let survivors = diversity_truncation(population, 2, 1.0);
+        assert_eq!(survivors.fitness, array![1.0, 0.0]);
+    }
+}
@@ -4,6 +4,9 @@ use crate::algorithms::AlgorithmBuilderError;
 use crate::evaluator::EvaluatorError;
 use crate::operators::evolve::EvolveError;
 
+#[cfg(feature = "result-cache")]
+use super::result_cache::ResultCacheError;
+
 /// Errors that can occur during initialization of the population.
 #[derive(Debug, Error)]
 pub enum InitializationError {
@@ -20,6 +23,20 @@ pub enum InitializationError {
     NotInitializated(String),
 }
 
+/// Errors of the pipelined ask/tell interface.
+#[derive(Debug, Error, PartialEq)]
+pub enum AskTellError {
+    /// `ask` was called while `pipeline_depth` batches were already in flight.
+    #[error("Pipeline is full: {0} batches are already waiting to be told")]
+    PipelineFull(usize),
+    /// `tell` was called with a ticket that is not in flight.
+    #[error("Unknown ticket {0}")]
+    UnknownTicket(usize),
+    /// The told fitness does not have one row per asked individual.
+    #[error("Expected fitness for {expected} individuals, got {found}")]
+    FitnessMismatch { expected: usize, found: usize },
+}
+
 #[derive(Debug, Error)]
 pub enum AlgorithmError {
     #[error("Error during evolution: {0}")]
@@ -30,4 +47,9 @@ pub enum AlgorithmError {
     ValidationError(#[from] AlgorithmBuilderError),
     #[error("Error during onitialization: {0}")]
     Initialization(#[from] InitializationError),
+    #[error("Ask/tell error: {0}")]
+    AskTell(#[from] AskTellError),
+    #[cfg(feature = "result-cache")]
+    #[error("Result cache error: {0}")]
+    ResultCache(#[from] ResultCacheError),
 }
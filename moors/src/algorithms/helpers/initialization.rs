@@ -7,10 +7,28 @@ use crate::{
     random::RandomGenerator,
 };
 
+/// The initialization step of every algorithm, usable on its own to get an evaluated initial
+/// population for a custom loop without building a whole algorithm.
 pub struct Initialization;
 
 impl Initialization {
     /// Sample, clean duplicates, evaluate, and rank the initial population.
+    ///
+    /// Guarantees:
+    /// - Exactly `context.population_size` individuals with `context.num_vars` genes are
+    ///   sampled. The returned population has `population_size` individuals unless some are
+    ///   removed by `duplicates_cleaner` or, when the evaluator does not keep infeasible
+    ///   individuals, by the feasibility filter; it is never larger.
+    /// - With `keep_infeasible(true)` (the evaluator default) infeasible individuals are kept and
+    ///   their constraint violation is available; otherwise only feasible individuals are
+    ///   returned and [`InitializationError::Evaluator`] is returned if there are none.
+    /// - `survivor` is run with `num_survive = population_size`, so no individual is discarded
+    ///   but the population carries the rank and survival score the selection operators need.
+    ///
+    /// The initial genes are evaluated with a single [`Evaluator::evaluate`] call, the same
+    /// path used for every generation in `next_pop`. The fitness and constraints functions
+    /// therefore always receive the whole batch, and a fitness wrapped in `ParallelFitness`
+    /// (feature `parallel`) evaluates the (possibly large) initial population in parallel too.
     pub fn initialize<S, Sur, DC, F, G>(
         sampler: &S,
         survivor: &mut Sur,
@@ -26,21 +44,63 @@ impl Initialization {
         F: FitnessFn,
         G: ConstraintsFn,
     {
-        // Get the initial genes
-        let mut genes = sampler.operate(context.population_size, context.num_vars, rng);
-        // If duplicates cleaner is passed then clean
-        genes = duplicates_cleaner.remove(genes, None);
-        // Do the first evaluation
-        let mut population = evaluator
-            .evaluate(genes)
-            .map_err(InitializationError::from)?;
+        let population =
+            Self::sample_and_evaluate(sampler, evaluator, duplicates_cleaner, rng, context)?;
         // Validate first individual
         // this step is very important. All members of the population survive, because
         // we use num_survive = context.population_size, but this step is adding the ranking
         // and the survival scorer (if the algorithm needs them), so in the selection step
         // we have all we need. See: https://github.com/andresliszt/moo-rs/issues/145
-        population = survivor.operate(population, context.population_size, rng);
-        Ok(population)
+        Ok(survivor.operate(population, context.population_size, rng))
+    }
+
+    /// Same as [`Self::initialize`], but the survivor ranks the initial population with
+    /// [`SurvivalOperator::operate_with_dominance_epsilon`].
+    pub fn initialize_with_dominance_epsilon<S, Sur, DC, F, G>(
+        sampler: &S,
+        survivor: &mut Sur,
+        evaluator: &mut Evaluator<F, G>,
+        duplicates_cleaner: &DC,
+        dominance_epsilon: f64,
+        rng: &mut impl RandomGenerator,
+        context: &AlgorithmContext,
+    ) -> Result<Population<F::Dim, G::Dim>, InitializationError>
+    where
+        S: SamplingOperator,
+        Sur: SurvivalOperator<FDim = F::Dim>,
+        DC: PopulationCleaner,
+        F: FitnessFn,
+        G: ConstraintsFn,
+    {
+        let population =
+            Self::sample_and_evaluate(sampler, evaluator, duplicates_cleaner, rng, context)?;
+        Ok(survivor.operate_with_dominance_epsilon(
+            population,
+            context.population_size,
+            dominance_epsilon,
+            rng,
+        ))
+    }
+
+    fn sample_and_evaluate<S, DC, F, G>(
+        sampler: &S,
+        evaluator: &mut Evaluator<F, G>,
+        duplicates_cleaner: &DC,
+        rng: &mut impl RandomGenerator,
+        context: &AlgorithmContext,
+    ) -> Result<Population<F::Dim, G::Dim>, InitializationError>
+    where
+        S: SamplingOperator,
+        DC: PopulationCleaner,
+        F: FitnessFn,
+        G: ConstraintsFn,
+    {
+        // Get the initial genes
+        let mut genes = sampler.operate(context.population_size, context.num_vars, rng);
+        // If duplicates cleaner is passed then clean
+        genes = duplicates_cleaner.remove(genes, None);
+        // Do the first evaluation
+        evaluator.evaluate(genes).map_err(InitializationError::from)
     }
 }
 
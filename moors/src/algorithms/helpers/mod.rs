@@ -1,8 +1,16 @@
 mod context;
+pub(in crate::algorithms) mod diversity;
 mod error;
+#[cfg(feature = "result-cache")]
+pub(in crate::algorithms) mod result_cache;
+mod rolling_stats;
 
 pub(in crate::algorithms) mod initialization;
 pub(in crate::algorithms) mod validators;
 
-pub(crate) use context::{AlgorithmContext, AlgorithmContextBuilder};
-pub use error::{AlgorithmError, InitializationError};
+pub use context::{AlgorithmContext, AlgorithmContextBuilder, AlgorithmContextBuilderError};
+pub use error::{AlgorithmError, AskTellError, InitializationError};
+pub use initialization::Initialization;
+#[cfg(feature = "result-cache")]
+pub use result_cache::{OptimizationResult, RESULT_CACHE_VERSION, ResultCacheError};
+pub use rolling_stats::RollingStats;
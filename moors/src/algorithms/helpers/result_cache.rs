@@ -0,0 +1,359 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use ndarray::{Array1, ArrayBase, ArrayD, Data, Dimension, Ix2, IxDyn};
+use thiserror::Error;
+
+use crate::{
+    evaluator::validate_weight,
+    genetic::{D12, Population},
+};
+
+/// Version tag written at the top of every cache file. Files written by another version of
+/// *moors* are ignored (treated as a cache miss) and overwritten by the next run.
+pub const RESULT_CACHE_VERSION: &str = concat!("moors-result-cache ", env!("CARGO_PKG_VERSION"));
+
+/// Errors of the on-disk result cache.
+#[derive(Debug, Error)]
+pub enum ResultCacheError {
+    #[error("Result cache I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Corrupted result cache file {path}: {reason}")]
+    Corrupted { path: PathBuf, reason: String },
+}
+
+/// Final state of a run: the last population and the number of evaluations it took.
+///
+/// This is what the result cache (enabled with `.result_cache(dir, problem_key)` on the algorithm
+/// builders)
+/// stores after a run and restores on an identical rerun.
+#[derive(Debug, Clone)]
+pub struct OptimizationResult<FDim = Ix2, ConstrDim = Ix2>
+where
+    FDim: D12,
+    ConstrDim: D12,
+{
+    pub population: Population<FDim, ConstrDim>,
+    pub num_evaluations: usize,
+}
+
+/// Cache directory, the user supplied key of the problem and the function describing the
+/// configuration `P` that keys the cache.
+pub(in crate::algorithms) struct ResultCacheConfig<P> {
+    dir: PathBuf,
+    problem_key: String,
+    describe: fn(&P) -> String,
+}
+
+impl<P> ResultCacheConfig<P> {
+    pub(in crate::algorithms) fn new(
+        dir: PathBuf,
+        problem_key: String,
+        describe: fn(&P) -> String,
+    ) -> Self {
+        Self {
+            dir,
+            problem_key,
+            describe,
+        }
+    }
+
+    pub(in crate::algorithms) fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub(in crate::algorithms) fn problem_key(&self) -> &str {
+        &self.problem_key
+    }
+
+    /// 64 bit FNV-1a hash of the version tag, the problem key and the configuration description.
+    /// Unlike `DefaultHasher`, it is stable across Rust releases, so cache files stay valid.
+    pub(in crate::algorithms) fn config_hash(&self, params: &P) -> u64 {
+        let description = format!(
+            "{RESULT_CACHE_VERSION}\nproblem_key={:?}\n{}",
+            self.problem_key,
+            (self.describe)(params)
+        );
+        description
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+            })
+    }
+}
+
+impl<P> fmt::Debug for ResultCacheConfig<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResultCacheConfig")
+            .field("dir", &self.dir)
+            .field("problem_key", &self.problem_key)
+            .finish()
+    }
+}
+
+/// Path of the cache file for the given configuration hash.
+pub(in crate::algorithms) fn cache_file(dir: &Path, config_hash: u64) -> PathBuf {
+    dir.join(format!("{config_hash:016x}.result"))
+}
+
+/// Reads a cached result. Returns `None` if the file does not exist or was written by another
+/// version of the crate.
+pub(in crate::algorithms) fn load<FDim, ConstrDim>(
+    path: &Path,
+) -> Result<Option<OptimizationResult<FDim, ConstrDim>>, ResultCacheError>
+where
+    FDim: D12,
+    ConstrDim: D12,
+{
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let mut lines = content.lines();
+    if lines.next() != Some(RESULT_CACHE_VERSION) {
+        return Ok(None);
+    }
+    let corrupted = |reason: String| ResultCacheError::Corrupted {
+        path: path.to_path_buf(),
+        reason,
+    };
+    let num_evaluations = lines
+        .next()
+        .and_then(|line| line.strip_prefix("num_evaluations "))
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| corrupted("missing number of evaluations".into()))?;
+    let mut read = |name: &str| read_array(&mut lines, name).map_err(&corrupted);
+
+    let genes = required(read("genes")?, "genes")
+        .and_then(|genes| into_dim::<Ix2>(genes, "genes"))
+        .map_err(&corrupted)?;
+    let fitness = required(read("fitness")?, "fitness")
+        .and_then(|fitness| into_dim::<FDim>(fitness, "fitness"))
+        .map_err(&corrupted)?;
+    let constraints = required(read("constraints")?, "constraints")
+        .and_then(|constraints| into_dim::<ConstrDim>(constraints, "constraints"))
+        .map_err(&corrupted)?;
+    let rank = read("rank")?;
+    let survival_score = read("survival_score")?;
+    let weight = read("weight")?;
+
+    let mut population = Population::new(genes, fitness, constraints);
+    if let Some(rank) = rank {
+        let rank: Array1<f64> = into_dim(rank, "rank").map_err(&corrupted)?;
+        population.set_rank(rank.mapv(|r| r as usize));
+    }
+    if let Some(survival_score) = survival_score {
+        population
+            .set_survival_score(into_dim(survival_score, "survival_score").map_err(&corrupted)?);
+    }
+    if let Some(weight) = weight {
+        let weight = into_dim(weight, "weight").map_err(&corrupted)?;
+        validate_weight(&weight, population.len()).map_err(|err| corrupted(err.to_string()))?;
+        population.set_weight(weight);
+    }
+    Ok(Some(OptimizationResult {
+        population,
+        num_evaluations,
+    }))
+}
+
+/// Writes a result to `path`, creating the cache directory if needed. The file is written next to
+/// its final location and then renamed, so an interrupted run never leaves a truncated file.
+pub(in crate::algorithms) fn store<FDim, ConstrDim>(
+    path: &Path,
+    result: &OptimizationResult<FDim, ConstrDim>,
+) -> Result<(), ResultCacheError>
+where
+    FDim: D12,
+    ConstrDim: D12,
+{
+    let population = &result.population;
+    let mut content = format!(
+        "{RESULT_CACHE_VERSION}\nnum_evaluations {}\n",
+        result.num_evaluations
+    );
+    write_array(&mut content, "genes", Some(&population.genes));
+    write_array(&mut content, "fitness", Some(&population.fitness));
+    write_array(&mut content, "constraints", Some(&population.constraints));
+    let rank: Option<Array1<f64>> = population.rank.as_ref().map(|r| r.mapv(|v| v as f64));
+    write_array(&mut content, "rank", rank.as_ref());
+    write_array(
+        &mut content,
+        "survival_score",
+        population.survival_score.as_ref(),
+    );
+    write_array(&mut content, "weight", population.weight.as_ref());
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, content)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Appends `name ndim shape...` (or `name none`) and a line with the values in logical order.
+/// Values are written with `Display`, which round-trips `f64` exactly.
+fn write_array<S, D>(content: &mut String, name: &str, array: Option<&ArrayBase<S, D>>)
+where
+    S: Data<Elem = f64>,
+    D: Dimension,
+{
+    let Some(array) = array else {
+        content.push_str(&format!("{name} none\n"));
+        return;
+    };
+    let shape: Vec<String> = array.shape().iter().map(ToString::to_string).collect();
+    content.push_str(&format!("{name} {} {}\n", array.ndim(), shape.join(" ")));
+    let values: Vec<String> = array.iter().map(ToString::to_string).collect();
+    content.push_str(&values.join(" "));
+    content.push('\n');
+}
+
+fn read_array<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    name: &str,
+) -> Result<Option<ArrayD<f64>>, String> {
+    let header = lines.next().ok_or(format!("missing {name}"))?;
+    let mut fields = header.split_whitespace();
+    if fields.next() != Some(name) {
+        return Err(format!("expected {name}, found '{header}'"));
+    }
+    let ndim = match fields.next() {
+        Some("none") => return Ok(None),
+        Some(ndim) => ndim
+            .parse::<usize>()
+            .map_err(|_| format!("invalid dimension of {name}"))?,
+        None => return Err(format!("missing dimension of {name}")),
+    };
+    let shape = fields
+        .map(str::parse::<usize>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| format!("invalid shape of {name}"))?;
+    if shape.len() != ndim {
+        return Err(format!("invalid shape of {name}"));
+    }
+    let values = lines
+        .next()
+        .ok_or(format!("missing values of {name}"))?
+        .split_whitespace()
+        .map(str::parse::<f64>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| format!("invalid value in {name}"))?;
+    ArrayD::from_shape_vec(IxDyn(&shape), values)
+        .map(Some)
+        .map_err(|_| format!("{name} values do not match its shape"))
+}
+
+fn required(array: Option<ArrayD<f64>>, name: &str) -> Result<ArrayD<f64>, String> {
+    array.ok_or(format!("{name} is required"))
+}
+
+fn into_dim<D: Dimension>(
+    array: ArrayD<f64>,
+    name: &str,
+) -> Result<ArrayBase<ndarray::OwnedRepr<f64>, D>, String> {
+    array
+        .into_dimensionality::<D>()
+        .map_err(|_| format!("{name} has an unexpected number of dimensions"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::PopulationMOO;
+    use ndarray::array;
+
+    #[test]
+    fn test_store_and_load_round_trip() {
+        let mut population = PopulationMOO::new(
+            array![[0.1, 0.2], [0.3, 1.0 / 3.0]],
+            array![[1.0, f64::INFINITY], [-2.5, 1e-300]],
+            array![[0.0], [0.5]],
+        );
+        population.set_rank(array![0, 1]);
+        population.set_survival_score(array![f64::INFINITY, 0.25]);
+        let result = OptimizationResult {
+            population,
+            num_evaluations: 42,
+        };
+        let dir = std::env::temp_dir().join(format!("moors_result_cache_{}", std::process::id()));
+        let path = cache_file(&dir, 0xabc);
+        store(&path, &result).expect("store failed");
+
+        let loaded: OptimizationResult<ndarray::Ix2, ndarray::Ix2> =
+            load(&path).expect("load failed").expect("cache miss");
+        assert_eq!(loaded.num_evaluations, 42);
+        assert_eq!(loaded.population.genes, result.population.genes);
+        assert_eq!(loaded.population.fitness, result.population.fitness);
+        assert_eq!(loaded.population.constraints, result.population.constraints);
+        assert_eq!(loaded.population.rank, result.population.rank);
+        assert_eq!(
+            loaded.population.survival_score,
+            result.population.survival_score
+        );
+        assert_eq!(loaded.population.weight, None);
+
+        // Files written by another version are a cache miss.
+        let content = fs::read_to_string(&path).unwrap();
+        fs::write(
+            &path,
+            content.replacen(RESULT_CACHE_VERSION, "moors-result-cache 0.0.0", 1),
+        )
+        .unwrap();
+        let loaded = load::<ndarray::Ix2, ndarray::Ix2>(&path).expect("load failed");
+        assert!(loaded.is_none());
+        // So are missing files, truncated ones are an error.
+        assert!(
+            load::<ndarray::Ix2, ndarray::Ix2>(&cache_file(&dir, 0xdef))
+                .unwrap()
+                .is_none()
+        );
+        fs::write(
+            &path,
+            format!("{RESULT_CACHE_VERSION}\nnum_evaluations 1\ngenes 2 1 1\n"),
+        )
+        .unwrap();
+        assert!(matches!(
+            load::<ndarray::Ix2, ndarray::Ix2>(&path),
+            Err(ResultCacheError::Corrupted { .. })
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_weights() {
+        let mut population = PopulationMOO::new_unconstrained(
+            array![[0.1, 0.2], [0.3, 0.4]],
+            array![[1.0, 2.0], [2.0, 1.0]],
+        );
+        population.set_weight(array![1.5, 2.0]);
+        let result = OptimizationResult {
+            population,
+            num_evaluations: 2,
+        };
+        let dir =
+            std::env::temp_dir().join(format!("moors_result_cache_weights_{}", std::process::id()));
+        let path = cache_file(&dir, 0xabc);
+        store(&path, &result).expect("store failed");
+        let loaded = load::<ndarray::Ix2, ndarray::Ix2>(&path)
+            .expect("load failed")
+            .expect("cache miss");
+        assert_eq!(loaded.population.weight, Some(array![1.5, 2.0]));
+
+        // A damaged file with non-positive or NaN weights is corrupted, not a panic.
+        let content = fs::read_to_string(&path).unwrap();
+        for invalid in ["0", "-1.5", "NaN"] {
+            fs::write(&path, content.replacen("1.5", invalid, 1)).unwrap();
+            assert!(matches!(
+                load::<ndarray::Ix2, ndarray::Ix2>(&path),
+                Err(ResultCacheError::Corrupted { .. })
+            ));
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
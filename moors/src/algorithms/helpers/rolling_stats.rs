@@ -0,0 +1,271 @@
+use ndarray::{Array1, Array2, Axis, Ix1, Ix2};
+
+use crate::genetic::{D12, Population};
+
+/// Running aggregates of an optimization run, kept in constant memory.
+///
+/// Enabled with `.rolling_stats(true)` on the algorithm builders. Instead of retaining past
+/// generations, the statistics are folded into a few fixed size values after every
+/// evaluation, plus the objectives of the current feasible survivors, so their storage is
+/// bounded by the population size whatever the number of generations (see
+/// [`num_stored_values`](Self::num_stored_values)):
+///
+/// | Statistic | Accessor | Meaning |
+/// |-----------|----------|---------|
+/// | Best so far | [`best_so_far`](Self::best_so_far) | Minimum value of each objective among all feasible survivors seen so far. |
+/// | Evaluations | [`num_evaluations`](Self::num_evaluations) | Cumulative number of individuals sent to the fitness function. |
+/// | Generations | [`num_generations`](Self::num_generations) | Number of completed generations (the initialization is not counted). |
+/// | Hypervolume | [`hypervolume`](Self::hypervolume) | Estimate of the hypervolume of the current feasible survivors, computed on demand. |
+///
+/// The hypervolume reference point is fixed at the first update: it is the worst value of each
+/// objective in the initial population, shifted by 10% of the objective range (or `1.0` if the
+/// range is zero). The volume is only estimated when [`Self::hypervolume`] is called, with
+/// [`Self::HYPERVOLUME_SAMPLES`] deterministic (Halton) samples, so the statistics cost nothing
+/// per generation when only the best-so-far is needed and do not alter the random stream of
+/// the algorithm.
+#[derive(Debug, Clone, Default)]
+pub struct RollingStats {
+    best_so_far: Option<Array1<f64>>,
+    num_evaluations: usize,
+    num_generations: usize,
+    reference_point: Option<Array1<f64>>,
+    /// Objectives of the current feasible survivors, kept to estimate the hypervolume on demand.
+    feasible_fitness: Option<Array2<f64>>,
+}
+
+impl RollingStats {
+    /// Number of quasi random samples used to estimate the hypervolume.
+    pub const HYPERVOLUME_SAMPLES: usize = 4096;
+
+    /// Minimum of each objective among the feasible survivors seen so far, `None` if no
+    /// feasible individual has been found yet.
+    pub fn best_so_far(&self) -> Option<&Array1<f64>> {
+        self.best_so_far.as_ref()
+    }
+
+    pub fn num_evaluations(&self) -> usize {
+        self.num_evaluations
+    }
+
+    pub fn num_generations(&self) -> usize {
+        self.num_generations
+    }
+
+    /// Reference point used by the hypervolume estimate, `None` before the first update.
+    pub fn reference_point(&self) -> Option<&Array1<f64>> {
+        self.reference_point.as_ref()
+    }
+
+    /// Estimates the hypervolume of the current feasible survivors, `0.0` if there are none.
+    /// Each call runs the [`Self::HYPERVOLUME_SAMPLES`] samples estimate.
+    pub fn hypervolume(&self) -> f64 {
+        match (&self.feasible_fitness, &self.reference_point) {
+            (Some(fitness), Some(reference_point)) => {
+                estimate_hypervolume(fitness, reference_point)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Number of floating point values held by the statistics. It is at most
+    /// `(population_size + 2) * num_objectives` and does not grow with the number of generations.
+    pub fn num_stored_values(&self) -> usize {
+        let len = |values: &Option<Array1<f64>>| values.as_ref().map_or(0, |v| v.len());
+        len(&self.best_so_far)
+            + len(&self.reference_point)
+            + self.feasible_fitness.as_ref().map_or(0, |f| f.len())
+    }
+
+    /// Folds the current survivors into the statistics. `num_evaluations` is the total number of
+    /// evaluations performed so far by the evaluator.
+    pub(in crate::algorithms) fn update<FDim, ConstrDim>(
+        &mut self,
+        population: &Population<FDim, ConstrDim>,
+        num_evaluations: usize,
+        is_generation: bool,
+    ) where
+        FDim: D12,
+        ConstrDim: D12,
+    {
+        self.num_evaluations = num_evaluations;
+        if is_generation {
+            self.num_generations += 1;
+        }
+        let fitness = fitness_as_2d(population);
+        self.reference_point
+            .get_or_insert_with(|| initial_reference_point(&fitness));
+
+        let feasible: Vec<usize> = match &population.constraint_violation_totals {
+            Some(cv) => (0..cv.len()).filter(|&i| cv[i] <= 0.0).collect(),
+            None => (0..fitness.nrows()).collect(),
+        };
+        if feasible.is_empty() {
+            self.feasible_fitness = None;
+            return;
+        }
+        let feasible_fitness = fitness.select(Axis(0), &feasible);
+        let current_best = feasible_fitness.fold_axis(Axis(0), f64::INFINITY, |&a, &b| a.min(b));
+        self.best_so_far = Some(match self.best_so_far.take() {
+            Some(best) => ndarray::Zip::from(&best)
+                .and(&current_best)
+                .map_collect(|&a, &b| a.min(b)),
+            None => current_best,
+        });
+        self.feasible_fitness = Some(feasible_fitness);
+    }
+}
+
+fn fitness_as_2d<FDim, ConstrDim>(population: &Population<FDim, ConstrDim>) -> Array2<f64>
+where
+    FDim: D12,
+    ConstrDim: D12,
+{
+    match FDim::NDIM {
+        Some(1) => population
+            .fitness
+            .view()
+            .into_dimensionality::<Ix1>()
+            .expect("Fitness is 1D")
+            .insert_axis(Axis(1))
+            .to_owned(),
+        _ => population
+            .fitness
+            .view()
+            .into_dimensionality::<Ix2>()
+            .expect("Fitness is 2D")
+            .to_owned(),
+    }
+}
+
+fn initial_reference_point(fitness: &Array2<f64>) -> Array1<f64> {
+    let nadir = fitness.fold_axis(Axis(0), f64::NEG_INFINITY, |&a, &b| a.max(b));
+    let ideal = fitness.fold_axis(Axis(0), f64::INFINITY, |&a, &b| a.min(b));
+    ndarray::Zip::from(&nadir)
+        .and(&ideal)
+        .map_collect(|&worst, &best| {
+            let range = worst - best;
+            if range > 0.0 {
+                worst + 0.1 * range
+            } else {
+                worst + 1.0
+            }
+        })
+}
+
+/// Estimates the volume dominated by `points` and bounded by `reference_point` with
+/// quasi random (Halton) samples drawn in the box `[ideal, reference_point]`.
+fn estimate_hypervolume(points: &Array2<f64>, reference_point: &Array1<f64>) -> f64 {
+    let num_objectives = points.ncols();
+    let inside: Vec<usize> = (0..points.nrows())
+        .filter(|&i| {
+            points
+                .row(i)
+                .iter()
+                .zip(reference_point.iter())
+                .all(|(&p, &r)| p < r)
+        })
+        .collect();
+    if inside.is_empty() {
+        return 0.0;
+    }
+    let points = points.select(Axis(0), &inside);
+    let lower = points.fold_axis(Axis(0), f64::INFINITY, |&a, &b| a.min(b));
+    let box_volume: f64 = reference_point
+        .iter()
+        .zip(lower.iter())
+        .map(|(&r, &l)| r - l)
+        .product();
+
+    let bases = first_primes(num_objectives);
+    let mut sample = vec![0.0; num_objectives];
+    let mut dominated = 0_usize;
+    for index in 1..=RollingStats::HYPERVOLUME_SAMPLES {
+        for (k, value) in sample.iter_mut().enumerate() {
+            *value = lower[k] + halton(index, bases[k]) * (reference_point[k] - lower[k]);
+        }
+        if points
+            .rows()
+            .into_iter()
+            .any(|p| p.iter().zip(sample.iter()).all(|(&pk, &sk)| pk <= sk))
+        {
+            dominated += 1;
+        }
+    }
+    box_volume * dominated as f64 / RollingStats::HYPERVOLUME_SAMPLES as f64
+}
+
+/// `index`-th element of the van der Corput sequence in the given `base`.
+fn halton(mut index: usize, base: usize) -> f64 {
+    let mut result = 0.0;
+    let mut fraction = 1.0 / base as f64;
+    while index > 0 {
+        result += fraction * (index % base) as f64;
+        index /= base;
+        fraction /= base as f64;
+    }
+    result
+}
+
+fn first_primes(n: usize) -> Vec<usize> {
+    let mut primes = Vec::with_capacity(n);
+    let mut candidate = 2;
+    while primes.len() < n {
+        if primes.iter().all(|&p| candidate % p != 0) {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::{PopulationMOO, PopulationSOO};
+    use ndarray::array;
+
+    #[test]
+    fn test_estimate_hypervolume_two_objectives() {
+        // Exact hypervolume w.r.t. [1, 1]: union of [0, 1] x [0.5, 1] and [0.5, 1] x [0, 1],
+        // that is 0.5 + 0.25 = 0.75.
+        let points = array![[0.0, 0.5], [0.5, 0.0]];
+        let hv = estimate_hypervolume(&points, &array![1.0, 1.0]);
+        assert!((hv - 0.75).abs() < 1e-2, "hv = {hv}");
+        // Points outside the reference box do not contribute.
+        let points = array![[2.0, 0.0]];
+        assert_eq!(estimate_hypervolume(&points, &array![1.0, 1.0]), 0.0);
+    }
+
+    #[test]
+    fn test_update_tracks_best_so_far_and_evaluations() {
+        let mut stats = RollingStats::default();
+        let population =
+            PopulationMOO::new_unconstrained(array![[0.0], [1.0]], array![[1.0, 3.0], [3.0, 1.0]]);
+        stats.update(&population, 2, false);
+        assert_eq!(stats.best_so_far(), Some(&array![1.0, 1.0]));
+        assert_eq!(stats.reference_point(), Some(&array![3.2, 3.2]));
+        assert_eq!(stats.num_generations(), 0);
+        assert!(stats.hypervolume() > 0.0);
+        // Best so far and reference point (2 values each) plus the 2 x 2 survivor objectives.
+        assert_eq!(stats.num_stored_values(), 8);
+
+        // A worse population does not make the best-so-far worse, the reference point is fixed.
+        let population =
+            PopulationMOO::new_unconstrained(array![[0.0], [1.0]], array![[0.5, 4.0], [4.0, 4.0]]);
+        stats.update(&population, 6, true);
+        assert_eq!(stats.best_so_far(), Some(&array![0.5, 1.0]));
+        assert_eq!(stats.reference_point(), Some(&array![3.2, 3.2]));
+        assert_eq!(stats.num_evaluations(), 6);
+        assert_eq!(stats.num_generations(), 1);
+        assert_eq!(stats.hypervolume(), 0.0);
+    }
+
+    #[test]
+    fn test_update_ignores_infeasible_individuals() {
+        let mut stats = RollingStats::default();
+        let population =
+            PopulationSOO::new(array![[0.0], [1.0]], array![-1.0, 2.0], array![1.0, 0.0]);
+        stats.update(&population, 2, false);
+        assert_eq!(stats.best_so_far(), Some(&array![2.0]));
+    }
+}
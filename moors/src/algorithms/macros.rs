@@ -58,10 +58,43 @@ macro_rules! create_algorithm {
                 self.inner.initialize()
             }
 
+            pub fn ask(&mut self) -> Result<$crate::algorithms::AskedOffspring, AlgorithmError> {
+                self.inner.ask()
+            }
+
+            pub fn tell(
+                &mut self,
+                ticket: usize,
+                fitness: ndarray::ArrayBase<ndarray::OwnedRepr<f64>, F::Dim>,
+            ) -> Result<(), AlgorithmError> {
+                self.inner.tell(ticket, fitness)
+            }
+
+            pub fn num_in_flight(&self) -> usize {
+                self.inner.num_in_flight()
+            }
+
             pub fn set_current_iteration(&mut self, current_iter: usize) {
                 self.inner.set_current_iteration(current_iter);
             }
 
+            /// Delegate `rolling_stats` to the inner algorithm
+            pub fn rolling_stats(&self) -> Option<&$crate::algorithms::RollingStats> {
+                self.inner.rolling_stats()
+            }
+
+            #[cfg(feature = "result-cache")]
+            pub fn config_hash(&self) -> Option<u64> {
+                self.inner.config_hash()
+            }
+
+            #[cfg(feature = "result-cache")]
+            pub fn optimization_result(
+                &self,
+            ) -> Option<$crate::algorithms::OptimizationResult<F::Dim, G::Dim>> {
+                self.inner.optimization_result()
+            }
+
             /// Delegate `population` to the inner algorithm
             pub fn population(
                 &self,
@@ -128,8 +161,36 @@ macro_rules! create_algorithm {
                 pub fn crossover_rate(mut self, v: f64) -> Self { self.inner_builder = self.inner_builder.crossover_rate(v); self }
                 pub fn keep_infeasible(mut self, v: bool) -> Self { self.inner_builder = self.inner_builder.keep_infeasible(v); self }
                 pub fn verbose(mut self, v: bool) -> Self { self.inner_builder = self.inner_builder.verbose(v); self }
+                pub fn pipeline_depth(mut self, v: usize) -> Self { self.inner_builder = self.inner_builder.pipeline_depth(v); self }
+                pub fn dominance_epsilon(mut self, v: f64) -> Self { self.inner_builder = self.inner_builder.dominance_epsilon(v); self }
+                pub fn weight_fn<W>(mut self, v: W) -> Self
+                where
+                    W: Fn(&ndarray::Array2<f64>) -> ndarray::Array1<f64> + Send + Sync + 'static,
+                {
+                    self.inner_builder = self.inner_builder.weight_fn(v);
+                    self
+                }
+                pub fn rolling_stats(mut self, v: bool) -> Self { self.inner_builder = self.inner_builder.rolling_stats(v); self }
                 pub fn seed(mut self, v: u64) -> Self { self.inner_builder = self.inner_builder.seed(v); self }
 
+                #[cfg(feature = "result-cache")]
+                pub fn result_cache(
+                    mut self,
+                    dir: impl Into<std::path::PathBuf>,
+                    problem_key: impl Into<String>,
+                ) -> Self
+                where
+                    S: std::fmt::Debug,
+                    $selector: std::fmt::Debug,
+                    $survivor: std::fmt::Debug,
+                    Cross: std::fmt::Debug,
+                    Mut: std::fmt::Debug,
+                    DC: std::fmt::Debug,
+                {
+                    self.inner_builder = self.inner_builder.result_cache(dir, problem_key);
+                    self
+                }
+
                 pub fn build(self) -> Result<$algorithm<S, Cross, Mut, F, G, DC>, AlgorithmBuilderError> {
                     Ok($algorithm {
                         inner: self.inner_builder.build()?,
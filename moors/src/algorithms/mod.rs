@@ -4,12 +4,18 @@ mod macros;
 mod moo;
 mod soo;
 
-pub use builder::{AlgorithmBuilder, AlgorithmBuilderError, GeneticAlgorithm};
+pub use builder::{AlgorithmBuilder, AlgorithmBuilderError, AskedOffspring, GeneticAlgorithm};
 pub use moo::agemoea::{AgeMoea, AgeMoeaBuilder};
+pub use moo::ibea::{Ibea, IbeaBuilder};
 pub use moo::nsga2::{Nsga2, Nsga2Builder};
 pub use moo::nsga3::{Nsga3, Nsga3Builder};
 pub use moo::revea::{Revea, ReveaBuilder};
 pub use moo::rnsga2::{Rnsga2, Rnsga2Builder};
 pub use moo::spea2::{Spea2, Spea2Builder};
 
-pub use helpers::{AlgorithmError, InitializationError};
+pub use helpers::{
+    AlgorithmContext, AlgorithmContextBuilder, AlgorithmContextBuilderError, AlgorithmError,
+    AskTellError, Initialization, InitializationError, RollingStats,
+};
+#[cfg(feature = "result-cache")]
+pub use helpers::{OptimizationResult, RESULT_CACHE_VERSION, ResultCacheError};
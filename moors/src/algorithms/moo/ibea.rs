@@ -0,0 +1,85 @@
+//! # IBEA – Indicator‑Based Evolutionary Algorithm
+//!
+//! Implementation of
+//! **Eckart Zitzler & Simon Künzli,
+//! “Indicator‑Based Selection in Multiobjective Search”,
+//! Parallel Problem Solving from Nature – PPSN VIII, LNCS 3242: 832‑842 (2004).**
+//!
+//! IBEA turns the multi‑objective problem into a single scalar fitness per
+//! individual: a *binary quality indicator* measures how much each solution is
+//! "better" than every other, and those pairwise values are aggregated with an
+//! exponential scaled by the factor `κ` (kappa). No dominance ranking nor
+//! decomposition is needed.
+//!
+//! In *moors*, IBEA is wired from reusable operator bricks:
+//!
+//! * **Selection:** [`RankAndScoringSelection`] (only survival‑score is used)
+//! * **Survival:**  [`IbeaSurvival`] (indicator‑based fitness, iterative truncation)
+//! * **Crossover / Mutation / Sampling:** user‑provided via the builder.
+//!
+//! The default survivor uses the **additive epsilon indicator** and
+//! `κ = 0.05`; pass [`IbeaSurvival::new`] to the builder to change either.
+
+use crate::{
+    create_algorithm,
+    selection::moo::RankAndScoringSelection,
+    survival::moo::{IbeaSurvival, SurvivalScoringComparison},
+};
+
+create_algorithm!(
+    /// IBEA algorithm wrapper.
+    ///
+    /// This struct is a thin facade over [`GeneticAlgorithm`] preset with
+    /// the IBEA survival and selection strategy.
+    ///
+    /// * **Selection:** [`RankAndScoringSelection`]
+    /// * **Survival:**  [`IbeaSurvival`] (elitist, additive epsilon indicator by default)
+    ///
+    /// Construct it with [`IbeaBuilder`](crate::algorithms::IbeaBuilder).
+    /// After building, call [`run`](GeneticAlgorithm::run)
+    /// and then [`population`](GeneticAlgorithm::population) to retrieve the
+    /// final population.
+    ///
+    /// For algorithmic details, see:
+    /// Eckart Zitzler and Simon Künzli (2004),
+    /// "Indicator-Based Selection in Multiobjective Search",
+    /// in *Parallel Problem Solving from Nature – PPSN VIII*,
+    /// LNCS vol. 3242, pp. 832–842, 2004.
+    /// DOI: 10.1007/978-3-540-30217-9_84
+    Ibea,
+    RankAndScoringSelection,
+    IbeaSurvival
+);
+
+impl<S, Cross, Mut, F, G, DC> Default for IbeaBuilder<S, Cross, Mut, F, G, DC>
+where
+    S: SamplingOperator,
+    Cross: CrossoverOperator,
+    Mut: MutationOperator,
+    F: FitnessFn<Dim = ndarray::Ix2>,
+    G: ConstraintsFn,
+    DC: PopulationCleaner,
+    AlgorithmBuilder<S, RankAndScoringSelection, IbeaSurvival, Cross, Mut, F, G, DC>: Default,
+{
+    fn default() -> Self {
+        let mut inner: AlgorithmBuilder<
+            S,
+            RankAndScoringSelection,
+            IbeaSurvival,
+            Cross,
+            Mut,
+            F,
+            G,
+            DC,
+        > = Default::default();
+
+        // Binary tournament on the indicator-based fitness only, larger is better
+        let selector =
+            RankAndScoringSelection::new(false, true, SurvivalScoringComparison::Maximize);
+
+        inner = inner.selector(selector).survivor(IbeaSurvival::default());
+        IbeaBuilder {
+            inner_builder: inner,
+        }
+    }
+}
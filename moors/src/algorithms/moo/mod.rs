@@ -10,6 +10,7 @@
 //! | **R‑NSGA‑II** | [`RankAndScoringSelection`](crate::operators::selection::rank_and_survival_scoring_tournament::RankAndScoringSelection) | [`Rnsga2ReferencePointsSurvival`](crate::operators::survival::rnsga2::Rnsga2ReferencePointsSurvival) | [`Rnsga2Builder`](crate::algorithms::Rnsga2Builder) |
 //! | **SPEA‑2** | [`RankAndScoringSelection`](crate::operators::selection::rank_and_survival_scoring_tournament::RankAndScoringSelection) | [`Spea2KnnSurvival`](crate::operators::survival::spea2::Spea2KnnSurvival) | [`Spea2Builder`](crate::algorithms::Spea2Builder) |
 //! | **AGE‑MOEA** | [`RankAndScoringSelection`](crate::operators::selection::rank_and_survival_scoring_tournament::RankAndScoringSelection) | [`AgeMoeaSurvival`](crate::operators::survival::agemoea::AgeMoeaSurvival) | [`AgeMoeaBuilder`](crate::algorithms::AgeMoeaBuilder) |
+//! | **IBEA** | [`RankAndScoringSelection`](crate::operators::selection::rank_and_survival_scoring_tournament::RankAndScoringSelection) | [`IbeaSurvival`](crate::operators::survival::ibea::IbeaSurvival) | [`IbeaBuilder`](crate::algorithms::IbeaBuilder) |
 //! | **REVEA** | [`RandomSelection`](crate::operators::selection::random_tournament::RandomSelection) | [`ReveaReferencePointsSurvival`](crate::operators::survival::revea::ReveaReferencePointsSurvival) | [`ReveaBuilder`](crate::algorithms::ReveaBuilder) |
 //!
 //! Each public algorithm struct (e.g. [`Nsga2`]) is a thin wrapper around
@@ -90,6 +91,7 @@
 //! operator or algorithm.
 
 pub(in crate::algorithms) mod agemoea;
+pub(in crate::algorithms) mod ibea;
 pub(in crate::algorithms) mod nsga2;
 pub(in crate::algorithms) mod nsga3;
 pub(in crate::algorithms) mod revea;
@@ -5,7 +5,7 @@
 //! takes a 2‑D array of genomes (`PopulationGenes` = `Array2<f64>`) and returns
 //! a fully populated [`Population`] with fitness values and optional constraints
 use derive_builder::Builder;
-use ndarray::{Array2, ArrayBase, Axis, Dimension, OwnedRepr};
+use ndarray::{Array1, Array2, ArrayBase, Axis, Dimension, OwnedRepr};
 use thiserror::Error;
 
 use crate::genetic::{D01, D12, Population};
@@ -72,11 +72,97 @@ where
     }
 }
 
+/// Fitness function evaluated in parallel on the rayon thread pool.
+///
+/// Each batch is split into chunks of rows, the wrapped function is called on every chunk
+/// concurrently and the results are concatenated back in the original row order. Every batch
+/// goes through [`Evaluator::evaluate`], so the initial population is evaluated in parallel
+/// exactly like every generation.
+#[cfg(feature = "parallel")]
+#[derive(Debug, Clone)]
+pub struct ParallelFitness<F> {
+    fitness: F,
+    chunk_size: Option<usize>,
+}
+
+#[cfg(feature = "parallel")]
+impl<F> ParallelFitness<F> {
+    /// Wraps `fitness`; by default each batch is split evenly across the threads of the
+    /// current rayon pool.
+    pub fn new(fitness: F) -> Self {
+        Self {
+            fitness,
+            chunk_size: None,
+        }
+    }
+
+    /// Evaluates at most `chunk_size` rows per call of the wrapped function.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than 0");
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<F, Dim> FitnessFn for ParallelFitness<F>
+where
+    F: Fn(&Array2<f64>) -> ArrayBase<OwnedRepr<f64>, Dim> + Sync,
+    Dim: D12 + Send,
+    <Dim as Dimension>::Smaller: D01,
+{
+    type Dim = Dim;
+    fn call(&mut self, genes: &Array2<f64>) -> ArrayBase<OwnedRepr<f64>, Dim> {
+        use rayon::prelude::*;
+
+        let n = genes.nrows();
+        if n == 0 {
+            return (self.fitness)(genes);
+        }
+        let chunk_size = self
+            .chunk_size
+            .unwrap_or_else(|| n.div_ceil(rayon::current_num_threads()));
+        let fitness = &self.fitness;
+        let chunks: Vec<ArrayBase<OwnedRepr<f64>, Dim>> = genes
+            .axis_chunks_iter(Axis(0), chunk_size)
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|chunk| fitness(&chunk.to_owned()))
+            .collect();
+        let views: Vec<_> = chunks.iter().map(|chunk| chunk.view()).collect();
+        ndarray::concatenate(Axis(0), &views)
+            .expect("every chunk must have the same number of objectives")
+    }
+}
+
 /// Error type for the Evaluator.
 #[derive(Debug, Error)]
 pub enum EvaluatorError {
     #[error("No feasible individuals found in the population.")]
     NoFeasibleIndividuals,
+    /// The weight function returned an unusable weight vector.
+    #[error("Invalid weights: {0}")]
+    InvalidWeights(String),
+}
+
+/// Checks that `weight` holds one strictly positive weight per individual, as required by
+/// [`Population::set_weight`].
+pub(crate) fn validate_weight(
+    weight: &Array1<f64>,
+    num_individuals: usize,
+) -> Result<(), EvaluatorError> {
+    if weight.len() != num_individuals {
+        return Err(EvaluatorError::InvalidWeights(format!(
+            "expected {num_individuals} weights, got {}",
+            weight.len()
+        )));
+    }
+    if let Some(w) = weight.iter().find(|&&w| w.is_nan() || w <= 0.0) {
+        return Err(EvaluatorError::InvalidWeights(format!(
+            "weights must be strictly positive, got {w}"
+        )));
+    }
+    Ok(())
 }
 
 /// Evaluator struct for calculating fitness and (optionally) constraints,
@@ -93,6 +179,8 @@ where
     constraints: G,
     #[builder(default = "true")]
     keep_infeasible: bool,
+    #[builder(setter(skip), default = "0")]
+    num_evaluations: usize,
 }
 
 impl<F, G> Evaluator<F, G>
@@ -100,17 +188,64 @@ where
     F: FitnessFn,
     G: ConstraintsFn,
 {
-    /// Builds the population instance from the genes. If `keep_infeasible` is false,
-    /// individuals are filtered out if they do not satisfy:
+    /// Total number of individuals evaluated so far.
+    pub fn num_evaluations(&self) -> usize {
+        self.num_evaluations
+    }
+
+    /// Builds the population instance from the genes. The initial population and every
+    /// generation are evaluated through the same weighted variant of this method, which holds
+    /// the only call of the fitness function, and the fitness function is called once with the
+    /// whole batch; wrap it in `ParallelFitness` (feature `parallel`) to split the batch across
+    /// threads. The pipelined ask/tell mode evaluates the fitness externally and uses
+    /// [`Self::evaluate_with_fitness`]. If `keep_infeasible` is false, individuals are filtered
+    /// out if they do not satisfy:
     ///   - The provided constraints function (all constraint values must be ≤ 0), and
     ///   - The optional lower and upper bounds (each gene must satisfy lower_bound <= gene <= upper_bound).
     pub fn evaluate(
         &mut self,
         genes: Array2<f64>,
+    ) -> Result<Population<F::Dim, G::Dim>, EvaluatorError> {
+        self.evaluate_weighted(genes, None)
+    }
+
+    /// Same as [`Self::evaluate`] for genes whose fitness has already been computed; only the
+    /// constraints function is called.
+    pub fn evaluate_with_fitness(
+        &mut self,
+        genes: Array2<f64>,
+        fitness: ArrayBase<OwnedRepr<f64>, F::Dim>,
+    ) -> Result<Population<F::Dim, G::Dim>, EvaluatorError> {
+        self.evaluate_with_fitness_weighted(genes, fitness, None)
+    }
+
+    /// Same as [`Self::evaluate`], setting `weight` (one per row of `genes`) on the population
+    /// before the infeasible individuals are filtered out, so every kept individual keeps its own.
+    /// Returns [`EvaluatorError::InvalidWeights`] unless there is one strictly positive weight
+    /// per row.
+    pub(crate) fn evaluate_weighted(
+        &mut self,
+        genes: Array2<f64>,
+        weight: Option<Array1<f64>>,
     ) -> Result<Population<F::Dim, G::Dim>, EvaluatorError> {
         let fitness = self.fitness.call(&genes);
+        self.evaluate_with_fitness_weighted(genes, fitness, weight)
+    }
+
+    /// Same as [`Self::evaluate_with_fitness`], with the weights of [`Self::evaluate_weighted`].
+    pub(crate) fn evaluate_with_fitness_weighted(
+        &mut self,
+        genes: Array2<f64>,
+        fitness: ArrayBase<OwnedRepr<f64>, F::Dim>,
+        weight: Option<Array1<f64>>,
+    ) -> Result<Population<F::Dim, G::Dim>, EvaluatorError> {
+        self.num_evaluations += genes.nrows();
         let constraints = self.constraints.call(&genes);
         let mut evaluated_population = Population::new(genes, fitness, constraints);
+        if let Some(weight) = weight {
+            validate_weight(&weight, evaluated_population.len())?;
+            evaluated_population.set_weight(weight);
+        }
 
         if !self.keep_infeasible {
             // Create a list of all indices.
@@ -345,4 +480,28 @@ mod tests {
         let expected = array![[5.0, 3.0], [25.0, 7.0]];
         assert_eq!(fit, expected);
     }
+
+    // ──────────────────────────────────────────────────────────────────────────
+    // Parallel fitness – chunks are concatenated back in row order
+    // ──────────────────────────────────────────────────────────────────────────
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_fitness_matches_sequential_fitness() {
+        let mut sequential = EvaluatorBuilder::default()
+            .fitness(fitness_2d_two_obj)
+            .constraints(NoConstraints)
+            .build()
+            .expect("Builder failed");
+        let mut parallel = EvaluatorBuilder::default()
+            .fitness(ParallelFitness::new(fitness_2d_two_obj).with_chunk_size(2))
+            .constraints(NoConstraints)
+            .build()
+            .expect("Builder failed");
+
+        let genes = array![[1.0, 2.0], [3.0, 4.0], [0.0, -1.0], [2.0, 2.0], [5.0, 0.5]];
+        let expected = sequential.evaluate(genes.clone()).unwrap().fitness;
+        assert_eq!(parallel.evaluate(genes).unwrap().fitness, expected);
+        assert_eq!(parallel.num_evaluations(), 5);
+    }
 }
@@ -115,7 +115,13 @@ where
 }
 
 /// The `Population` struct contains genes, fitness, constraints (if any),
-/// rank (optional), and optionally a survival score vector.
+/// rank (optional), optionally a survival score vector and optional per-individual
+/// weights.
+///
+/// Weights carry external information about each individual (e.g. a robustness estimate)
+/// that survival operators may use to break ties between otherwise equivalent individuals.
+/// `None` means uniform weights (all equal to `1.0`), which leaves every operator's behavior
+/// unchanged.
 #[derive(Debug, Clone)]
 pub struct Population<FDim = Ix2, ConstrDim = Ix2>
 where
@@ -128,6 +134,7 @@ where
     pub rank: Option<Array1<usize>>,
     pub survival_score: Option<Array1<f64>>,
     pub constraint_violation_totals: Option<Array1<f64>>,
+    pub weight: Option<Array1<f64>>,
 }
 
 impl<FDim, ConstrDim> Population<FDim, ConstrDim>
@@ -165,6 +172,7 @@ where
             rank: None,
             survival_score: None,
             constraint_violation_totals: constraint_violation,
+            weight: None,
         }
     }
 
@@ -207,6 +215,7 @@ where
             .survival_score
             .as_ref()
             .map(|ss| ss.select(Axis(0), indices));
+        let weight = self.weight.as_ref().map(|w| w.select(Axis(0), indices));
         Population {
             genes,
             fitness,
@@ -214,6 +223,7 @@ where
             rank,
             survival_score,
             constraint_violation_totals,
+            weight,
         }
     }
 
@@ -252,6 +262,31 @@ where
         self.rank = Some(rank);
     }
 
+    /// Updates the population's `weight` field.
+    ///
+    /// # Panics
+    /// Panics if the length doesn't match the population size or if any weight is not
+    /// strictly positive.
+    pub fn set_weight(&mut self, weight: Array1<f64>) {
+        assert_eq!(
+            weight.len(),
+            self.len(),
+            "Weight length must match the population size"
+        );
+        assert!(
+            weight.iter().all(|&w| w > 0.0),
+            "Weights must be strictly positive"
+        );
+        self.weight = Some(weight);
+    }
+
+    /// Returns the per-individual weights, `1.0` for everyone if no weights are set.
+    pub fn weights(&self) -> Array1<f64> {
+        self.weight
+            .clone()
+            .unwrap_or_else(|| Array1::ones(self.len()))
+    }
+
     /// Merges two populations into one.
     pub fn merge(
         population1: &Population<FDim, ConstrDim>,
@@ -311,6 +346,18 @@ where
             _ => panic!("Mismatched population survival scores: one is set and the other is None"),
         };
 
+        // Merge weight: a missing side is treated as uniform weights.
+        let merged_weight = match (&population1.weight, &population2.weight) {
+            (None, None) => None,
+            _ => Some(
+                concatenate(
+                    Axis(0),
+                    &[population1.weights().view(), population2.weights().view()],
+                )
+                .expect("Failed to merge weights"),
+            ),
+        };
+
         Population {
             genes: merged_genes,
             fitness: merged_fitness,
@@ -318,6 +365,7 @@ where
             rank: merged_rank,
             survival_score: merged_survival_score,
             constraint_violation_totals: merged_total_cv,
+            weight: merged_weight,
         }
     }
 }
@@ -335,10 +383,99 @@ where
             rank: None,
             survival_score: None,
             constraint_violation_totals: None,
+            weight: None,
+        }
+    }
+}
+
+impl<ConstrDim> Population<Ix1, ConstrDim>
+where
+    ConstrDim: D12,
+{
+    /// Returns the best individual: the one with the lowest fitness among the feasible
+    /// individuals, or among the least violating ones if no individual is feasible.
+    ///
+    /// # Panics
+    /// Panics if the population is empty.
+    pub fn best_solution(&self) -> BestSolution<f64> {
+        let idx = self
+            .least_violating_indices()
+            .into_iter()
+            .min_by(|&i, &j| self.fitness[i].total_cmp(&self.fitness[j]))
+            .expect("Cannot get the best solution of an empty population");
+        self.best_solution_at(idx, self.fitness[idx])
+    }
+}
+
+impl<ConstrDim> Population<Ix2, ConstrDim>
+where
+    ConstrDim: D12,
+{
+    /// Returns a single representative of the (feasible) Pareto front: the *knee* individual,
+    /// i.e. the one closest to the ideal point once each objective is normalized to `[0, 1]`
+    /// over the candidates. Candidates are the feasible individuals, or the least violating
+    /// ones if no individual is feasible. The knee is never dominated by another candidate.
+    ///
+    /// # Panics
+    /// Panics if the population is empty.
+    pub fn representative(&self) -> BestSolution<Array1<f64>> {
+        let candidates = self.least_violating_indices();
+        let fitness = self.fitness.select(Axis(0), &candidates);
+        let ideal = fitness.fold_axis(Axis(0), f64::INFINITY, |&a, &b| a.min(b));
+        let nadir = fitness.fold_axis(Axis(0), f64::NEG_INFINITY, |&a, &b| a.max(b));
+        let range = (&nadir - &ideal).mapv(|r| if r > 0.0 { r } else { 1.0 });
+        let distances = ((&fitness - &ideal) / &range)
+            .mapv(|v| v * v)
+            .sum_axis(Axis(1));
+        let knee = (0..candidates.len())
+            .min_by(|&i, &j| distances[i].total_cmp(&distances[j]))
+            .expect("Cannot get the representative of an empty population");
+        let idx = candidates[knee];
+        self.best_solution_at(idx, self.fitness.row(idx).to_owned())
+    }
+}
+
+impl<FDim, ConstrDim> Population<FDim, ConstrDim>
+where
+    FDim: D12,
+    ConstrDim: D12,
+{
+    /// Indices of the feasible individuals, or of the least violating ones if none is feasible.
+    fn least_violating_indices(&self) -> Vec<usize> {
+        let Some(cv) = &self.constraint_violation_totals else {
+            return (0..self.len()).collect();
+        };
+        let min_cv = cv.iter().copied().fold(f64::INFINITY, f64::min);
+        (0..self.len()).filter(|&i| cv[i] <= min_cv).collect()
+    }
+
+    fn best_solution_at<Fit>(&self, idx: usize, fitness: Fit) -> BestSolution<Fit> {
+        let violation = self
+            .constraint_violation_totals
+            .as_ref()
+            .map_or(0.0, |cv| cv[idx]);
+        BestSolution {
+            genes: self.genes.row(idx).to_owned(),
+            fitness,
+            feasible: violation <= 0.0,
+            violation,
         }
     }
 }
 
+/// An owned snapshot of a single solution, as returned by
+/// [`PopulationSOO::best_solution`] (`Fit = f64`) and
+/// [`PopulationMOO::representative`] (`Fit = Array1<f64>`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BestSolution<Fit = f64> {
+    pub genes: Array1<f64>,
+    /// Fitness value (SOO) or objective vector (MOO).
+    pub fitness: Fit,
+    pub feasible: bool,
+    /// Total constraint violation, `0.0` for feasible (or unconstrained) solutions.
+    pub violation: f64,
+}
+
 /// Type alias for Population in Multi Objective Optimization
 pub type PopulationMOO<ConstrDim = Ix2> = Population<Ix2, ConstrDim>;
 /// Type alias for Population in Single Objective Optimization
@@ -531,6 +668,32 @@ mod tests {
         assert_eq!(merged.rank, expected_rank, "Merged rank does not match");
     }
 
+    #[test]
+    fn test_population_weight_selected_and_merge() {
+        let genes = array![[1.0, 2.0], [3.0, 4.0]];
+        let fitness = array![[0.5, 1.0], [1.5, 2.0]];
+        let mut pop1 = PopulationMOO::new_unconstrained(genes.clone(), fitness.clone());
+        assert_eq!(pop1.weights(), array![1.0, 1.0]);
+        pop1.set_weight(array![2.0, 3.0]);
+        assert_eq!(pop1.selected(&[1]).weight, Some(array![3.0]));
+
+        // A population without weights is merged as uniform weights.
+        let pop2 = PopulationMOO::new_unconstrained(genes, fitness);
+        let merged = PopulationMOO::merge(&pop1, &pop2);
+        assert_eq!(merged.weight, Some(array![2.0, 3.0, 1.0, 1.0]));
+        let merged = PopulationMOO::merge(&pop2, &pop2);
+        assert_eq!(merged.weight, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Weights must be strictly positive")]
+    fn test_population_non_positive_weight_panics() {
+        let genes = array![[1.0, 2.0]];
+        let fitness = array![[0.5, 1.0]];
+        let mut pop = PopulationMOO::new_unconstrained(genes, fitness);
+        pop.set_weight(array![0.0]);
+    }
+
     #[test]
     fn test_fronts_ext_to_population_moo() {
         // Create two fronts.
@@ -573,6 +736,38 @@ mod tests {
         Population::merge(&pop1, &pop2);
     }
 
+    #[test]
+    fn test_population_soo_best_solution() {
+        let genes = array![[0.0], [1.0], [2.0], [3.0]];
+        let fitness = array![0.5, -1.0, 0.1, 2.0];
+        // Individual 1 has the lowest fitness but is infeasible.
+        let constraints = array![-1.0, 1.0, 0.0, -2.0];
+        let pop = PopulationSOO::new(genes, fitness, constraints);
+        let best = pop.best_solution();
+        assert_eq!(best.genes, array![2.0]);
+        assert_eq!(best.fitness, 0.1);
+        assert!(best.feasible);
+        assert_eq!(best.violation, 0.0);
+
+        // Without feasible individuals the least violating one is returned.
+        let pop = PopulationSOO::new(array![[0.0], [1.0]], array![0.0, 1.0], array![2.0, 1.0]);
+        let best = pop.best_solution();
+        assert_eq!(best.genes, array![1.0]);
+        assert!(!best.feasible);
+        assert!(best.violation > 0.0);
+    }
+
+    #[test]
+    fn test_population_moo_representative_is_knee() {
+        let genes = array![[0.0], [1.0], [2.0], [3.0]];
+        let fitness = array![[0.0, 1.0], [0.2, 0.3], [1.0, 0.0], [0.5, 0.5]];
+        let pop = PopulationMOO::new_unconstrained(genes, fitness);
+        let representative = pop.representative();
+        assert_eq!(representative.genes, array![1.0]);
+        assert_eq!(representative.fitness, array![0.2, 0.3]);
+        assert!(representative.feasible);
+    }
+
     #[test]
     fn test_individual_soo_with_and_without_constraints() {
         // Unconstrained individual: fitness is 0-D
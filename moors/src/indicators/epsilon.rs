@@ -0,0 +1,169 @@
+use ndarray::{Array2, ArrayView1};
+
+use crate::indicators::IndicatorError;
+
+fn validate_fronts(front: &Array2<f64>, reference: &Array2<f64>) -> Result<(), IndicatorError> {
+    if front.nrows() == 0 {
+        return Err(IndicatorError::EmptyFront("evaluated"));
+    }
+    if reference.nrows() == 0 {
+        return Err(IndicatorError::EmptyFront("reference"));
+    }
+    if front.ncols() != reference.ncols() {
+        return Err(IndicatorError::ObjectivesMismatch {
+            front: front.ncols(),
+            reference: reference.ncols(),
+        });
+    }
+    Ok(())
+}
+
+/// Computes `max_{r ∈ reference} min_{a ∈ front} max_k shift(a_k, r_k)`.
+fn epsilon_indicator(
+    front: &Array2<f64>,
+    reference: &Array2<f64>,
+    shift: impl Fn(f64, f64) -> f64,
+) -> f64 {
+    let point_epsilon = |a: &ArrayView1<f64>, r: &ArrayView1<f64>| {
+        a.iter()
+            .zip(r.iter())
+            .map(|(&ak, &rk)| shift(ak, rk))
+            .fold(f64::NEG_INFINITY, f64::max)
+    };
+    reference
+        .rows()
+        .into_iter()
+        .map(|r| {
+            front
+                .rows()
+                .into_iter()
+                .map(|a| point_epsilon(&a, &r))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+/// Additive epsilon indicator `I_ε+(front, reference)`.
+///
+/// Returns the minimum `ε` such that every point of `reference` is weakly dominated by
+/// some point of `front` translated by `−ε` in every objective:
+///
+/// ```text
+/// I_ε+(A, R) = max_{r ∈ R} min_{a ∈ A} max_k (a_k − r_k)
+/// ```
+///
+/// # Errors
+/// * [`IndicatorError::EmptyFront`] if either front has no rows.
+/// * [`IndicatorError::ObjectivesMismatch`] if the number of columns differ.
+pub fn epsilon_additive(
+    front: &Array2<f64>,
+    reference: &Array2<f64>,
+) -> Result<f64, IndicatorError> {
+    validate_fronts(front, reference)?;
+    Ok(epsilon_indicator(front, reference, |a, r| a - r))
+}
+
+/// Multiplicative epsilon indicator `I_ε×(front, reference)`.
+///
+/// Returns the minimum `ε` such that every point of `reference` is weakly dominated by
+/// some point of `front` scaled by `1 / ε` in every objective:
+///
+/// ```text
+/// I_ε×(A, R) = max_{r ∈ R} min_{a ∈ A} max_k (a_k / r_k)
+/// ```
+///
+/// # Errors
+/// * [`IndicatorError::EmptyFront`] if either front has no rows.
+/// * [`IndicatorError::ObjectivesMismatch`] if the number of columns differ.
+/// * [`IndicatorError::NonPositiveObjectives`] if any objective value is `≤ 0`.
+pub fn epsilon_multiplicative(
+    front: &Array2<f64>,
+    reference: &Array2<f64>,
+) -> Result<f64, IndicatorError> {
+    validate_fronts(front, reference)?;
+    if front.iter().chain(reference.iter()).any(|&v| v <= 0.0) {
+        return Err(IndicatorError::NonPositiveObjectives);
+    }
+    Ok(epsilon_indicator(front, reference, |a, r| a / r))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_epsilon_additive_hand_computed() {
+        let front = array![[1.0, 3.0], [2.0, 2.0], [3.0, 1.0]];
+        let reference = array![[0.5, 3.0], [2.0, 1.5], [2.5, 0.5]];
+        // r1 = [0.5, 3.0]: min(max(0.5, 0.0), max(1.5, -1.0), max(2.5, -2.0)) = 0.5
+        // r2 = [2.0, 1.5]: min(max(-1.0, 1.5), max(0.0, 0.5), max(1.0, -0.5)) = 0.5
+        // r3 = [2.5, 0.5]: min(max(-1.5, 2.5), max(-0.5, 1.5), max(0.5, 0.5)) = 0.5
+        let value = epsilon_additive(&front, &reference).unwrap();
+        assert!((value - 0.5).abs() < 1e-12);
+        // The other way around every front point is weakly dominated by a reference point.
+        // a1 = [1.0, 3.0]: min(max(-0.5, 0.0), max(1.0, -1.5), max(1.5, -2.5)) = 0.0
+        // a2 = [2.0, 2.0]: min(max(-1.5, 1.0), max(0.0, -0.5), max(0.5, -1.5)) = 0.0
+        // a3 = [3.0, 1.0]: min(max(-2.5, 2.0), max(-1.0, 0.5), max(-0.5, -0.5)) = -0.5
+        let value = epsilon_additive(&reference, &front).unwrap();
+        assert_eq!(value, 0.0);
+    }
+
+    #[test]
+    fn test_epsilon_additive_dominating_front_is_non_positive() {
+        let front = array![[0.0, 1.0], [1.0, 0.0]];
+        let reference = array![[0.5, 1.5], [1.5, 0.5]];
+        let value = epsilon_additive(&front, &reference).unwrap();
+        assert!((value + 0.5).abs() < 1e-12);
+        let value = epsilon_additive(&front, &front).unwrap();
+        assert_eq!(value, 0.0);
+    }
+
+    #[test]
+    fn test_epsilon_multiplicative_hand_computed() {
+        let front = array![[2.0, 4.0], [4.0, 2.0]];
+        let reference = array![[1.0, 4.0], [4.0, 1.0]];
+        // r1 = [1.0, 4.0]: min(max(2.0, 1.0), max(4.0, 0.5)) = 2.0
+        // r2 = [4.0, 1.0]: min(max(0.5, 4.0), max(1.0, 2.0)) = 2.0
+        let value = epsilon_multiplicative(&front, &reference).unwrap();
+        assert!((value - 2.0).abs() < 1e-12);
+        let value = epsilon_multiplicative(&front, &front).unwrap();
+        assert_eq!(value, 1.0);
+    }
+
+    #[test]
+    fn test_empty_fronts_are_errors() {
+        let empty = Array2::<f64>::zeros((0, 2));
+        let front = array![[1.0, 2.0]];
+        assert_eq!(
+            epsilon_additive(&empty, &front),
+            Err(IndicatorError::EmptyFront("evaluated"))
+        );
+        assert_eq!(
+            epsilon_additive(&front, &empty),
+            Err(IndicatorError::EmptyFront("reference"))
+        );
+        assert_eq!(
+            epsilon_multiplicative(&empty, &front),
+            Err(IndicatorError::EmptyFront("evaluated"))
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs_are_errors() {
+        let front = array![[1.0, 2.0]];
+        let reference = array![[1.0, 2.0, 3.0]];
+        assert_eq!(
+            epsilon_additive(&front, &reference),
+            Err(IndicatorError::ObjectivesMismatch {
+                front: 2,
+                reference: 3
+            })
+        );
+        let non_positive = array![[0.0, 2.0]];
+        assert_eq!(
+            epsilon_multiplicative(&front, &non_positive),
+            Err(IndicatorError::NonPositiveObjectives)
+        );
+    }
+}
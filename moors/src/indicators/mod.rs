@@ -0,0 +1,43 @@
+//! # `indicators` – Comparing Pareto Front Approximations
+//!
+//! Quality indicators turn a whole front (an `N × M` matrix where each row is
+//! the objective vector of one solution) into a single number, which makes it
+//! possible to compare the output of different algorithms, seeds or settings.
+//!
+//! | Indicator | Function | Meaning |
+//! |-----------|----------|---------|
+//! | Additive epsilon       | [`epsilon_additive`]       | Smallest `ε` such that `front − ε` weakly dominates `reference`. |
+//! | Multiplicative epsilon | [`epsilon_multiplicative`] | Smallest `ε` such that `front / ε` weakly dominates `reference` (positive objectives only). |
+//!
+//! All objectives are assumed to be **minimized**. Lower values are better: an
+//! additive value `≤ 0` (multiplicative `≤ 1`) means `front` already weakly
+//! dominates `reference`.
+//!
+//! ```rust
+//! use ndarray::array;
+//! use moors::indicators::epsilon_additive;
+//!
+//! let front = array![[1.0, 2.0], [2.0, 1.0]];
+//! let reference = array![[0.5, 2.0], [2.0, 0.5]];
+//! assert_eq!(epsilon_additive(&front, &reference).unwrap(), 0.5);
+//! ```
+
+mod epsilon;
+
+use thiserror::Error;
+
+pub use epsilon::{epsilon_additive, epsilon_multiplicative};
+
+/// Errors that can occur when computing a quality indicator.
+#[derive(Debug, Error, PartialEq)]
+pub enum IndicatorError {
+    /// One of the fronts has no solutions, so the indicator is undefined.
+    #[error("The {0} front is empty")]
+    EmptyFront(&'static str),
+    /// Both fronts must have the same number of objectives.
+    #[error("Number of objectives mismatch: front has {front}, reference has {reference}")]
+    ObjectivesMismatch { front: usize, reference: usize },
+    /// The multiplicative epsilon indicator needs strictly positive objectives.
+    #[error("Multiplicative epsilon indicator requires strictly positive objectives")]
+    NonPositiveObjectives,
+}
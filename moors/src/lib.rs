@@ -22,6 +22,7 @@
 //! |--------|------------|
 //! | NSGA   | **NSGA‑II**, NSGA‑III, RNSGA‑II |
 //! | SPEA   | SPEA‑2 |
+//! | Indicator | IBEA |
 //! | Others | AGE‑MOEA, REVEA *(WIP)* |
 //!
 //! ## Quick start
@@ -85,6 +86,7 @@
 //! * [`evaluator`](crate::evaluator)  – fitness + constraints evaluation pipeline
 //! * [`random`](crate::random)        – pluggable RNG abstraction
 //! * [`duplicates`](crate::duplicates) – duplicate‑handling strategies
+//! * [`indicators`](crate::indicators) – quality indicators to compare fronts
 //!
 //! ---
 
@@ -97,21 +99,29 @@ pub mod duplicates;
 pub mod evaluator;
 pub mod genetic;
 pub(crate) mod helpers;
+pub mod indicators;
 pub mod non_dominated_sorting;
 pub mod operators;
 mod private;
 pub mod random;
 pub use algorithms::{
-    AgeMoea, AgeMoeaBuilder, AlgorithmBuilder, AlgorithmBuilderError, AlgorithmError,
-    GeneticAlgorithm, InitializationError, Nsga2, Nsga2Builder, Nsga3, Nsga3Builder, Revea,
-    ReveaBuilder, Rnsga2, Rnsga2Builder, Spea2, Spea2Builder,
+    AgeMoea, AgeMoeaBuilder, AlgorithmBuilder, AlgorithmBuilderError, AlgorithmContext,
+    AlgorithmContextBuilder, AlgorithmContextBuilderError, AlgorithmError, AskTellError,
+    AskedOffspring, GeneticAlgorithm, Ibea, IbeaBuilder, Initialization, InitializationError,
+    Nsga2, Nsga2Builder, Nsga3, Nsga3Builder, Revea, ReveaBuilder, Rnsga2, Rnsga2Builder,
+    RollingStats, Spea2, Spea2Builder,
 };
+#[cfg(feature = "result-cache")]
+pub use algorithms::{OptimizationResult, RESULT_CACHE_VERSION, ResultCacheError};
 pub use duplicates::{
     CloseDuplicatesCleaner, ExactDuplicatesCleaner, NoDuplicatesCleaner, PopulationCleaner,
 };
+#[cfg(feature = "parallel")]
+pub use evaluator::ParallelFitness;
 pub use evaluator::{ConstraintsFn, EvaluatorError, FitnessFn, NoConstraints};
 pub use genetic::{
-    Individual, IndividualMOO, IndividualSOO, Population, PopulationMOO, PopulationSOO,
+    BestSolution, Individual, IndividualMOO, IndividualSOO, Population, PopulationMOO,
+    PopulationSOO,
 };
 pub use helpers::linalg::cross_euclidean_distances;
 pub use operators::selection;
@@ -119,14 +129,15 @@ pub use operators::survival;
 pub use operators::{
     AgeMoeaSurvival, ArithmeticCrossover, BitFlipMutation, CrossoverOperator,
     DanAndDenisReferencePoints, DisplacementMutation, ExponentialCrossover,
-    FrontsAndRankingBasedSurvival, GaussianMutation, InversionMutation, MutationOperator,
-    NormalBoundaryDivisions, Nsga2RankCrowdingSurvival, Nsga3ReferencePoints,
-    Nsga3ReferencePointsSurvival, OrderCrossover, PerGeneSampling, PermutationSampling,
-    PolynomialMutation, RandomSamplingBinary, RandomSamplingFloat, RandomSamplingInt,
-    RandomSelectionMOO, RankAndScoringSelectionMOO, ReveaReferencePointsSurvival,
-    Rnsga2ReferencePointsSurvival, SBXCrossover, SamplingOperator, ScrambleMutation,
-    SelectionOperator, SimulatedBinaryCrossover, SinglePointBinaryCrossover, Spea2KnnSurvival,
-    StructuredReferencePoints, SurvivalOperator, SwapMutation, TwoPointBinaryCrossover,
-    UniformBinaryCrossover, UniformBinaryMutation, UniformRealMutation, evolve::EvolveError,
+    FrontsAndRankingBasedSurvival, GaussianMutation, IbeaIndicator, IbeaSurvival,
+    InversionMutation, LoadedReferencePoints, MutationOperator, NormalBoundaryDivisions,
+    Nsga2RankCrowdingSurvival, Nsga3ReferencePoints, Nsga3ReferencePointsSurvival, OrderCrossover,
+    PerGeneSampling, PermutationSampling, PolynomialMutation, RandomSamplingBinary,
+    RandomSamplingFloat, RandomSamplingInt, RandomSelectionMOO, RankAndScoringSelectionMOO,
+    ReferencePointsError, ReveaReferencePointsSurvival, Rnsga2ReferencePointsSurvival,
+    SBXCrossover, SamplingOperator, ScrambleMutation, SelectionOperator, SimulatedBinaryCrossover,
+    SinglePointBinaryCrossover, Spea2KnnSurvival, StructuredReferencePoints, SurvivalOperator,
+    SwapMutation, TwoPointBinaryCrossover, UniformBinaryCrossover, UniformBinaryMutation,
+    UniformRealMutation, evolve::EvolveError,
 };
 pub use random::{MOORandomGenerator, NoopRandomGenerator, RandomGenerator, TestDummyRng};
@@ -3,14 +3,18 @@ use ndarray::{Array1, Array2, ArrayView1, Axis};
 use crate::genetic::{D12, Fronts, PopulationMOO};
 
 /// Inlines the check for "does f1 dominate f2?" to reduce call overhead.
+///
+/// Objective values whose difference is within `epsilon` are considered equal, so `f1`
+/// dominates `f2` if no objective is worse by more than `epsilon` and at least one is better
+/// by more than `epsilon`. With `epsilon = 0` this is the usual strict Pareto dominance.
 #[inline]
-fn dominates(f1: &ArrayView1<f64>, f2: &ArrayView1<f64>) -> bool {
+fn dominates(f1: &ArrayView1<f64>, f2: &ArrayView1<f64>, epsilon: f64) -> bool {
     let mut better = false;
     // We assume f1.len() == f2.len()
     for (&a, &b) in f1.iter().zip(f2.iter()) {
-        if a > b {
+        if a - b > epsilon {
             return false;
-        } else if a < b {
+        } else if b - a > epsilon {
             better = true;
         }
     }
@@ -26,6 +30,17 @@ fn dominates(f1: &ArrayView1<f64>, f2: &ArrayView1<f64>) -> bool {
 pub fn fast_non_dominated_sorting(
     population_fitness: &Array2<f64>,
     min_survivors: usize,
+) -> Vec<Vec<usize>> {
+    fast_non_dominated_sorting_with_epsilon(population_fitness, min_survivors, 0.0)
+}
+
+/// Same as [`fast_non_dominated_sorting`], but objective values differing by at most
+/// `dominance_epsilon` are treated as equal when checking dominance. This keeps
+/// numerically noisy, nearly identical points in the same front.
+pub fn fast_non_dominated_sorting_with_epsilon(
+    population_fitness: &Array2<f64>,
+    min_survivors: usize,
+    dominance_epsilon: f64,
 ) -> Vec<Vec<usize>> {
     let population_size = population_fitness.shape()[0];
 
@@ -41,11 +56,11 @@ pub fn fast_non_dominated_sorting(
     // Sequential pairwise comparisons: for each pair (p, q) with p < q
     for p in 0..population_size {
         for q in (p + 1)..population_size {
-            if dominates(&fitness_rows[p], &fitness_rows[q]) {
+            if dominates(&fitness_rows[p], &fitness_rows[q], dominance_epsilon) {
                 // p dominates q
                 dominated_sets[p].push(q);
                 domination_count[q] += 1;
-            } else if dominates(&fitness_rows[q], &fitness_rows[p]) {
+            } else if dominates(&fitness_rows[q], &fitness_rows[p], dominance_epsilon) {
                 // q dominates p
                 dominated_sets[q].push(p);
                 domination_count[p] += 1;
@@ -53,62 +68,71 @@ pub fn fast_non_dominated_sorting(
         }
     }
 
-    // Build the first front
+    // Build the fronts. `assigned` marks the individuals that already belong to a front.
     let mut fronts = Vec::new();
-    let mut first_front = Vec::new();
+    let mut assigned = vec![false; population_size];
+    let mut count = 0;
+    let mut current_front: Vec<usize> = (0..population_size)
+        .filter(|&i| domination_count[i] == 0)
+        .collect();
 
-    for (i, item) in domination_count.iter().enumerate().take(population_size) {
-        if *item == 0 {
-            first_front.push(i);
+    loop {
+        // With a positive epsilon dominance is not transitive and cycles (a ≻ b ≻ c ≻ a) may
+        // leave individuals whose domination count never reaches zero. Break the cycle by
+        // taking the least dominated of the remaining individuals as the next front.
+        if current_front.is_empty() && count < population_size {
+            let min_count = (0..population_size)
+                .filter(|&i| !assigned[i])
+                .map(|i| domination_count[i])
+                .min()
+                .unwrap_or(0);
+            current_front = (0..population_size)
+                .filter(|&i| !assigned[i] && domination_count[i] == min_count)
+                .collect();
         }
-    }
-
-    fronts.push(first_front.clone());
-    let mut count = first_front.len();
-    // If the first front already reaches min_survivors, return immediately.
-    if count >= min_survivors {
-        return fronts;
-    }
-
-    // Construct subsequent fronts
-    let mut current_front = first_front;
-    while !current_front.is_empty() {
+        for &p in &current_front {
+            assigned[p] = true;
+        }
+        count += current_front.len();
+        fronts.push(current_front.clone());
+        // If the fronts already reach min_survivors (including the entire last front) or
+        // every individual is assigned, stop the construction.
+        if count >= min_survivors || count == population_size {
+            break;
+        }
+        // Construct the next front
         let mut next_front = Vec::new();
         for &p in &current_front {
             for &q in &dominated_sets[p] {
+                if assigned[q] {
+                    continue;
+                }
                 domination_count[q] -= 1;
                 if domination_count[q] == 0 {
                     next_front.push(q);
                 }
             }
         }
-        if next_front.is_empty() {
-            break;
-        }
-        // If adding the next front reaches or exceeds min_survivors,
-        // include the entire front and stop the construction.
-        if count + next_front.len() >= min_survivors {
-            fronts.push(next_front);
-            break;
-        } else {
-            count += next_front.len();
-            fronts.push(next_front.clone());
-            current_front = next_front;
-        }
+        current_front = next_front;
     }
 
     fronts
 }
 
-/// Builds the fronts from the population.
+/// Builds the fronts from the population, see [`fast_non_dominated_sorting_with_epsilon`].
 pub fn build_fronts<ConstrDim>(
     population: PopulationMOO<ConstrDim>,
     num_survive: usize,
+    dominance_epsilon: f64,
 ) -> Fronts<ConstrDim>
 where
     ConstrDim: D12,
 {
-    let sorted_fronts = fast_non_dominated_sorting(&population.fitness, num_survive);
+    let sorted_fronts = fast_non_dominated_sorting_with_epsilon(
+        &population.fitness,
+        num_survive,
+        dominance_epsilon,
+    );
     let mut results: Fronts<ConstrDim> = Vec::new();
     // For each front (with rank = front_index), extract the sub-population.
     for (front_index, indices) in sorted_fronts.iter().enumerate() {
@@ -129,22 +153,67 @@ mod tests {
         // Test case 1: The first vector dominates the second
         let a = array![1.0, 2.0, 3.0];
         let b = array![2.0, 3.0, 4.0];
-        assert_eq!(dominates(&a.view(), &b.view()), true);
+        assert!(dominates(&a.view(), &b.view(), 0.0));
 
         // Test case 2: The second vector dominates the first
         let a = array![3.0, 3.0, 3.0];
         let b = array![2.0, 4.0, 5.0];
-        assert_eq!(dominates(&a.view(), &b.view()), false);
+        assert!(!dominates(&a.view(), &b.view(), 0.0));
 
         // Test case 3: Neither vector dominates the other
         let a = array![1.0, 2.0, 3.0];
         let b = array![2.0, 1.0, 3.0];
-        assert_eq!(dominates(&a.view(), &b.view()), false);
+        assert!(!dominates(&a.view(), &b.view(), 0.0));
 
         // Test case 4: Equal vectors
         let a = array![1.0, 2.0, 3.0];
         let b = array![1.0, 2.0, 3.0];
-        assert_eq!(dominates(&a.view(), &b.view()), false);
+        assert!(!dominates(&a.view(), &b.view(), 0.0));
+    }
+
+    #[test]
+    fn test_dominates_with_epsilon() {
+        // Differences of 1e-12 are numerical noise under a small epsilon.
+        let a = array![1.0, 2.0];
+        let b = array![1.0 + 1e-12, 2.0 + 1e-12];
+        assert!(dominates(&a.view(), &b.view(), 0.0));
+        assert!(!dominates(&a.view(), &b.view(), 1e-9));
+        assert!(!dominates(&b.view(), &a.view(), 1e-9));
+
+        // Differences larger than epsilon still count.
+        let b = array![1.0 + 1e-12, 2.5];
+        assert!(dominates(&a.view(), &b.view(), 1e-9));
+    }
+
+    #[test]
+    fn test_fast_non_dominated_sorting_with_epsilon() {
+        let population_fitness = array![
+            [1.0, 2.0],                 // Individual 0
+            [1.0 + 1e-12, 2.0 + 1e-12], // Individual 1: individual 0 up to float noise
+            [3.0, 3.0]                  // Individual 2: dominated by both
+        ];
+        let fronts = fast_non_dominated_sorting(&population_fitness, 3);
+        assert_eq!(fronts, vec![vec![0], vec![1], vec![2]]);
+
+        let fronts = fast_non_dominated_sorting_with_epsilon(&population_fitness, 3, 1e-9);
+        assert_eq!(fronts, vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_fast_non_dominated_sorting_with_epsilon_cycle() {
+        // With epsilon = 1 individuals 0 ≻ 1 ≻ 2 ≻ 0 form a dominance cycle, and all of them
+        // dominate individual 3. Nobody is left out of the fronts.
+        let population_fitness = array![
+            [0.470, 1.101, 2.060],
+            [1.598, 0.383, 1.065],
+            [0.634, 1.578, 0.572],
+            [5.0, 5.0, 5.0]
+        ];
+        let fronts = fast_non_dominated_sorting_with_epsilon(&population_fitness, 4, 1.0);
+        assert_eq!(fronts, vec![vec![0, 1, 2], vec![3]]);
+
+        let fronts = fast_non_dominated_sorting_with_epsilon(&population_fitness, 2, 1.0);
+        assert_eq!(fronts, vec![vec![0, 1, 2]]);
     }
 
     #[test]
@@ -254,7 +323,7 @@ mod tests {
         let population = PopulationMOO::new(genes, fitness, constraints);
 
         // Call build_fronts with num_survive = 5.
-        let fronts = build_fronts(population, 5);
+        let fronts = build_fronts(population, 5, 0.0);
 
         // We expect three fronts:
         //   Front 0: 1 individual (the best).
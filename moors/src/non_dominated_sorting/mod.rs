@@ -1,4 +1,4 @@
 mod fds;
 
 pub(crate) use fds::build_fronts;
-pub use fds::fast_non_dominated_sorting;
+pub use fds::{fast_non_dominated_sorting, fast_non_dominated_sorting_with_epsilon};
@@ -68,12 +68,12 @@ pub trait CrossoverOperator {
             if rng.gen_probability() <= crossover_rate {
                 // Perform crossover
                 let (child_a, child_b) = self.crossover(&parent_a, &parent_b, rng);
-                flat_offspring.extend(child_a.into_iter());
-                flat_offspring.extend(child_b.into_iter());
+                flat_offspring.extend(child_a);
+                flat_offspring.extend(child_b);
             } else {
                 // Keep parents as offspring
-                flat_offspring.extend(parent_a.into_iter());
-                flat_offspring.extend(parent_b.into_iter());
+                flat_offspring.extend(parent_a);
+                flat_offspring.extend(parent_b);
             }
         }
 
@@ -72,6 +72,7 @@ pub mod mutation;
 pub mod sampling;
 pub mod selection;
 pub mod survival;
+pub mod validation;
 
 pub use crossover::{
     ArithmeticCrossover, CrossoverOperator, ExponentialCrossover, OrderCrossover, SBXCrossover,
@@ -97,9 +98,11 @@ pub use selection::{
 pub use survival::{
     SurvivalOperator,
     moo::{
-        AgeMoeaSurvival, DanAndDenisReferencePoints, FrontsAndRankingBasedSurvival,
-        NormalBoundaryDivisions, Nsga2RankCrowdingSurvival, Nsga3ReferencePoints,
-        Nsga3ReferencePointsSurvival, ReveaReferencePointsSurvival, Rnsga2ReferencePointsSurvival,
-        Spea2KnnSurvival, StructuredReferencePoints,
+        AgeMoeaSurvival, DanAndDenisReferencePoints, FrontsAndRankingBasedSurvival, IbeaIndicator,
+        IbeaSurvival, LoadedReferencePoints, NormalBoundaryDivisions, Nsga2RankCrowdingSurvival,
+        Nsga3ReferencePoints, Nsga3ReferencePointsSurvival, ReferencePointsError,
+        ReveaReferencePointsSurvival, Rnsga2ReferencePointsSurvival, Spea2KnnSurvival,
+        StructuredReferencePoints,
     },
 };
+pub use validation::{OperatorValidationContext, OperatorValidationError, validate_operator};
@@ -77,7 +77,7 @@ mod tests {
             let x1 = sample[0];
             let x2 = sample[1];
             // check ranges
-            assert!(x1 >= -10.0 && x1 <= 1.0 && x2 >= 0.0 && x2 <= 10.0);
+            assert!((-10.0..=1.0).contains(&x1) && (0.0..=10.0).contains(&x2));
         }
     }
 }
@@ -130,50 +130,52 @@ mod tests {
     #[test]
     fn test_default_diversity_comparison_maximize() {
         let selector = RankAndScoringSelection::default();
-        match selector.survival_comparison {
-            SurvivalScoringComparison::Maximize => assert!(true),
-            SurvivalScoringComparison::Minimize => panic!("Default should be Maximize"),
-        }
+        assert!(
+            matches!(
+                selector.survival_comparison,
+                SurvivalScoringComparison::Maximize
+            ),
+            "Default should be Maximize"
+        );
         // default uses both
         assert!(selector.use_rank);
         assert!(selector.use_survival_score);
     }
 
     #[rstest(
-        left_feasible, right_feasible, left_rank, right_rank, left_survival, right_survival, survival_comparison, expected,
+        left, right, survival_comparison, expected,
+        // Each side is given as (feasible, rank, survival score).
         // Feasibility check: if one is feasible and the other isn't, feasibility wins regardless of rank or survival.
-        case(true, false, 0, 1, 10.0, 5.0, SurvivalScoringComparison::Maximize, DuelResult::LeftWins),
-        case(false, true, 1, 0, 10.0, 5.0, SurvivalScoringComparison::Maximize, DuelResult::RightWins),
+        case((true, 0, 10.0), (false, 1, 5.0), SurvivalScoringComparison::Maximize, DuelResult::LeftWins),
+        case((false, 1, 10.0), (true, 0, 5.0), SurvivalScoringComparison::Maximize, DuelResult::RightWins),
 
         // Both are feasible: rank comparison takes precedence.
-        case(true, true, 0, 1, 5.0, 10.0, SurvivalScoringComparison::Maximize, DuelResult::LeftWins),
-        case(true, true, 2, 1, 5.0, 10.0, SurvivalScoringComparison::Maximize, DuelResult::RightWins),
+        case((true, 0, 5.0), (true, 1, 10.0), SurvivalScoringComparison::Maximize, DuelResult::LeftWins),
+        case((true, 2, 5.0), (true, 1, 10.0), SurvivalScoringComparison::Maximize, DuelResult::RightWins),
 
         // Both are feasible (or both infeasible) and ranks are equal → decide by survival (diversity) in Maximize mode.
-        case(true, true, 0, 0, 10.0, 5.0, SurvivalScoringComparison::Maximize, DuelResult::LeftWins),
-        case(true, true, 0, 0, 5.0, 10.0, SurvivalScoringComparison::Maximize, DuelResult::RightWins),
-        case(true, true, 0, 0, 7.0, 7.0, SurvivalScoringComparison::Maximize, DuelResult::Tie),
+        case((true, 0, 10.0), (true, 0, 5.0), SurvivalScoringComparison::Maximize, DuelResult::LeftWins),
+        case((true, 0, 5.0), (true, 0, 10.0), SurvivalScoringComparison::Maximize, DuelResult::RightWins),
+        case((true, 0, 7.0), (true, 0, 7.0), SurvivalScoringComparison::Maximize, DuelResult::Tie),
 
         // Both are feasible (or both infeasible) and ranks are equal → decide by survival in Minimize mode.
-        case(true, true, 0, 0, 5.0, 10.0, SurvivalScoringComparison::Minimize, DuelResult::LeftWins),
-        case(true, true, 0, 0, 10.0, 5.0, SurvivalScoringComparison::Minimize, DuelResult::RightWins),
-        case(true, true, 0, 0, 8.0, 8.0, SurvivalScoringComparison::Minimize, DuelResult::Tie),
+        case((true, 0, 5.0), (true, 0, 10.0), SurvivalScoringComparison::Minimize, DuelResult::LeftWins),
+        case((true, 0, 10.0), (true, 0, 5.0), SurvivalScoringComparison::Minimize, DuelResult::RightWins),
+        case((true, 0, 8.0), (true, 0, 8.0), SurvivalScoringComparison::Minimize, DuelResult::Tie),
 
         // Both are infeasible: return the one with smaller constraint violations. In this test case
         // both have the same constraint violation totals, continue with rank and survival comparison
-        case(false, false, 0, 1, 10.0, 5.0, SurvivalScoringComparison::Maximize, DuelResult::LeftWins),
-        case(false, false, 0, 0, 7.0, 7.0, SurvivalScoringComparison::Maximize, DuelResult::Tie)
+        case((false, 0, 10.0), (false, 1, 5.0), SurvivalScoringComparison::Maximize, DuelResult::LeftWins),
+        case((false, 0, 7.0), (false, 0, 7.0), SurvivalScoringComparison::Maximize, DuelResult::Tie)
     )]
     fn test_tournament_duel(
-        left_feasible: bool,
-        right_feasible: bool,
-        left_rank: usize,
-        right_rank: usize,
-        left_survival: f64,
-        right_survival: f64,
+        left: (bool, usize, f64),
+        right: (bool, usize, f64),
         survival_comparison: SurvivalScoringComparison,
         expected: DuelResult,
     ) {
+        let (left_feasible, left_rank, left_survival) = left;
+        let (right_feasible, right_rank, right_survival) = right;
         // For simplicity, we use the same genes and fitness values for both individuals.
         let genes = array![1.0, 2.0];
         let fitness = array![0.5];
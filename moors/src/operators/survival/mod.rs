@@ -24,4 +24,21 @@ pub trait SurvivalOperator {
     ) -> Population<Self::FDim, ConstrDim>
     where
         ConstrDim: D12;
+
+    /// Same as [`Self::operate`], but objective values differing by at most `dominance_epsilon`
+    /// are considered equal when checking Pareto dominance (see the algorithm builders'
+    /// `dominance_epsilon`). Operators that do not rank by dominance ignore it, which is the
+    /// default.
+    fn operate_with_dominance_epsilon<ConstrDim>(
+        &mut self,
+        population: Population<Self::FDim, ConstrDim>,
+        num_survive: usize,
+        _dominance_epsilon: f64,
+        rng: &mut impl RandomGenerator,
+    ) -> Population<Self::FDim, ConstrDim>
+    where
+        ConstrDim: D12,
+    {
+        self.operate(population, num_survive, rng)
+    }
 }
@@ -394,7 +394,7 @@ mod tests {
         assert!(scores[0].is_infinite(), "Index 0 should be extreme (∞)");
         assert!(scores[2].is_infinite(), "Index 2 should be extreme (∞)");
         let candidate_score = scores[1];
-        let expected_score = 1.0 / 0.70710678; // ~1.41421356
+        let expected_score = std::f64::consts::SQRT_2; // 1 / 0.70710678
         assert!(
             (candidate_score - expected_score).abs() < 1e-6,
             "Score for index 1 should be ~1.41421356"
@@ -0,0 +1,256 @@
+use ndarray::{Array1, Array2, ArrayView1, Axis};
+
+use crate::{
+    genetic::{D12, PopulationMOO},
+    helpers::extreme_points::{get_ideal, get_nadir},
+    operators::survival::SurvivalOperator,
+    random::RandomGenerator,
+};
+
+/// Binary quality indicator used by IBEA to compare two individuals.
+///
+/// Objectives are normalized to `[0, 1]` (using the ideal and nadir points of the
+/// population) before the indicator is evaluated.
+#[derive(Debug, Clone, Default)]
+pub enum IbeaIndicator {
+    /// Additive epsilon indicator `I_ε+(a, b) = max_k (a_k - b_k)`: the smallest
+    /// shift that makes `a` weakly dominate `b`. This is the default.
+    #[default]
+    AdditiveEpsilon,
+    /// Hypervolume difference `I_HD(a, b)` computed against the reference point
+    /// `(2.0, ..., 2.0)` in the normalized objective space.
+    Hypervolume,
+}
+
+impl IbeaIndicator {
+    /// Reference point coordinate used by the hypervolume indicator in the normalized space.
+    const HV_REFERENCE: f64 = 2.0;
+
+    /// Evaluates `I(a, b)` for two normalized objective vectors.
+    fn compute(&self, a: &ArrayView1<f64>, b: &ArrayView1<f64>) -> f64 {
+        match self {
+            IbeaIndicator::AdditiveEpsilon => a
+                .iter()
+                .zip(b.iter())
+                .map(|(&ak, &bk)| ak - bk)
+                .fold(f64::NEG_INFINITY, f64::max),
+            IbeaIndicator::Hypervolume => {
+                let volume = |x: &ArrayView1<f64>| {
+                    x.iter()
+                        .map(|&xk| (Self::HV_REFERENCE - xk).max(0.0))
+                        .product::<f64>()
+                };
+                let hv_a = volume(a);
+                let hv_b = volume(b);
+                let a_dominates_b = a.iter().zip(b.iter()).all(|(&ak, &bk)| ak <= bk);
+                if a_dominates_b {
+                    hv_b - hv_a
+                } else {
+                    // HV({a, b}) - HV({a}) = HV(b) - HV(max(a, b))
+                    let joint: Array1<f64> = a
+                        .iter()
+                        .zip(b.iter())
+                        .map(|(&ak, &bk)| ak.max(bk))
+                        .collect();
+                    hv_b - volume(&joint.view())
+                }
+            }
+        }
+    }
+}
+
+/// Implementation of the survival operator for the IBEA algorithm presented in the paper
+/// Indicator-Based Selection in Multiobjective Search.
+///
+/// Each individual gets the fitness
+///
+/// ```text
+/// F(x) = Σ_{y ≠ x} −exp(−I(y, x) / (c · κ))
+/// ```
+///
+/// where `I` is the binary [`IbeaIndicator`] (additive epsilon by default), `c` is the maximum
+/// absolute indicator value in the population and `κ` (`kappa`) is the scaling factor. The
+/// individual with the lowest fitness is removed iteratively (updating the fitness of the rest)
+/// until `num_survive` remain. The final fitness is stored as survival score, larger is better.
+#[derive(Debug, Clone)]
+pub struct IbeaSurvival {
+    kappa: f64,
+    indicator: IbeaIndicator,
+}
+
+impl IbeaSurvival {
+    /// Default fitness scaling factor suggested in the original paper.
+    pub const DEFAULT_KAPPA: f64 = 0.05;
+
+    pub fn new(kappa: f64, indicator: IbeaIndicator) -> Self {
+        assert!(kappa > 0.0, "IbeaSurvival: kappa must be greater than 0");
+        Self { kappa, indicator }
+    }
+
+    pub fn kappa(&self) -> f64 {
+        self.kappa
+    }
+
+    pub fn indicator(&self) -> &IbeaIndicator {
+        &self.indicator
+    }
+}
+
+impl Default for IbeaSurvival {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_KAPPA, IbeaIndicator::default())
+    }
+}
+
+impl SurvivalOperator for IbeaSurvival {
+    type FDim = ndarray::Ix2;
+
+    fn operate<ConstrDim>(
+        &mut self,
+        population: PopulationMOO<ConstrDim>,
+        num_survive: usize,
+        _rng: &mut impl RandomGenerator,
+    ) -> PopulationMOO<ConstrDim>
+    where
+        ConstrDim: D12,
+    {
+        let normalized = normalize_fitness(&population.fitness);
+        let indicator_matrix = compute_indicator_matrix(&normalized, &self.indicator);
+        let scale = indicator_matrix
+            .iter()
+            .fold(0.0_f64, |acc, &v| acc.max(v.abs()))
+            .max(f64::EPSILON)
+            * self.kappa;
+        // contributions[[i, j]] = exp(-I(i, j) / (c * kappa)), how much i penalizes j
+        let contributions = indicator_matrix.mapv(|v| (-v / scale).exp());
+        let mut fitness = compute_fitness(&contributions);
+        let survivors = environmental_selection(&contributions, &mut fitness, num_survive);
+
+        let mut result = population.selected(&survivors);
+        result.set_survival_score(fitness.select(Axis(0), &survivors));
+        result
+    }
+}
+
+/// Normalizes each objective to `[0, 1]` using the ideal and nadir points.
+fn normalize_fitness(population_fitness: &Array2<f64>) -> Array2<f64> {
+    let z_min = get_ideal(population_fitness);
+    let z_max = get_nadir(population_fitness);
+    let range = (&z_max - &z_min).mapv(|r| if r > 0.0 { r } else { 1.0 });
+    (population_fitness - &z_min) / &range
+}
+
+/// Computes the n×n matrix with entries `I(x_i, x_j)`.
+fn compute_indicator_matrix(
+    normalized_fitness: &Array2<f64>,
+    indicator: &IbeaIndicator,
+) -> Array2<f64> {
+    let n = normalized_fitness.nrows();
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        if i == j {
+            0.0
+        } else {
+            indicator.compute(&normalized_fitness.row(i), &normalized_fitness.row(j))
+        }
+    })
+}
+
+/// F(x_j) = Σ_{i ≠ j} −contributions[[i, j]]
+fn compute_fitness(contributions: &Array2<f64>) -> Array1<f64> {
+    let n = contributions.nrows();
+    Array1::from_shape_fn(n, |j| {
+        -(0..n)
+            .filter(|&i| i != j)
+            .map(|i| contributions[[i, j]])
+            .sum::<f64>()
+    })
+}
+
+/// Iteratively removes the individual with the smallest fitness, updating the fitness of the
+/// remaining ones, until `num_survive` individuals are left. Returns the surviving indices.
+fn environmental_selection(
+    contributions: &Array2<f64>,
+    fitness: &mut Array1<f64>,
+    num_survive: usize,
+) -> Vec<usize> {
+    let mut alive: Vec<usize> = (0..fitness.len()).collect();
+    while alive.len() > num_survive {
+        let (worst_position, &worst) = alive
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| fitness[**a].total_cmp(&fitness[**b]))
+            .expect("population can not be empty");
+        alive.swap_remove(worst_position);
+        for &j in &alive {
+            fitness[j] += contributions[[worst, j]];
+        }
+    }
+    alive.sort_unstable();
+    alive
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::NoopRandomGenerator;
+    use ndarray::array;
+
+    #[test]
+    fn test_additive_epsilon_indicator() {
+        let a = array![0.2, 0.5];
+        let b = array![0.4, 0.1];
+        // max(0.2 - 0.4, 0.5 - 0.1) = 0.4
+        let value = IbeaIndicator::AdditiveEpsilon.compute(&a.view(), &b.view());
+        assert!((value - 0.4).abs() < 1e-12);
+        // max(0.4 - 0.2, 0.1 - 0.5) = 0.2
+        let value = IbeaIndicator::AdditiveEpsilon.compute(&b.view(), &a.view());
+        assert!((value - 0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hypervolume_indicator() {
+        // a dominates b: I_HD(a, b) = HV(b) - HV(a) = 1.0 * 1.0 - 2.0 * 2.0 = -3.0
+        let a = array![0.0, 0.0];
+        let b = array![1.0, 1.0];
+        let value = IbeaIndicator::Hypervolume.compute(&a.view(), &b.view());
+        assert!((value + 3.0).abs() < 1e-12);
+        // b doesn't dominate a: I_HD(b, a) = HV(a) - HV(max(a, b)) = 4.0 - 1.0 = 3.0
+        let value = IbeaIndicator::Hypervolume.compute(&b.view(), &a.view());
+        assert!((value - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_dominated_individual_is_removed() {
+        // Individual 2 is dominated by individual 1 and must be the one removed.
+        let genes = array![[0.0], [1.0], [2.0], [3.0]];
+        let fitness = array![[0.0, 1.0], [0.5, 0.5], [0.6, 0.6], [1.0, 0.0]];
+        let population = PopulationMOO::new_unconstrained(genes, fitness);
+        let mut survivor = IbeaSurvival::default();
+        let mut rng = NoopRandomGenerator::new();
+        let survivors = survivor.operate(population, 3, &mut rng);
+
+        assert_eq!(survivors.len(), 3);
+        assert_eq!(survivors.genes, array![[0.0], [1.0], [3.0]]);
+        assert!(survivors.survival_score.is_some());
+    }
+
+    #[test]
+    fn test_all_survive_when_num_survive_is_population_size() {
+        let genes = array![[0.0], [1.0], [2.0]];
+        let fitness = array![[0.0, 1.0], [0.5, 0.5], [1.0, 0.0]];
+        let population = PopulationMOO::new_unconstrained(genes.clone(), fitness);
+        let mut survivor = IbeaSurvival::new(0.5, IbeaIndicator::Hypervolume);
+        let mut rng = NoopRandomGenerator::new();
+        let survivors = survivor.operate(population, 3, &mut rng);
+
+        assert_eq!(survivors.genes, genes);
+        let scores = survivors.survival_score.unwrap();
+        assert!(scores.iter().all(|&s| s.is_finite() && s < 0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "kappa must be greater than 0")]
+    fn test_non_positive_kappa_panics() {
+        IbeaSurvival::new(0.0, IbeaIndicator::AdditiveEpsilon);
+    }
+}
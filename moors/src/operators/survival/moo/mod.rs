@@ -7,6 +7,7 @@ use crate::{
 
 pub(crate) mod agemoea;
 pub(crate) mod helpers;
+pub(crate) mod ibea;
 pub(crate) mod nsga2;
 pub(crate) mod nsga3;
 pub(crate) mod reference_points;
@@ -15,10 +16,12 @@ pub(crate) mod rnsga2;
 pub(crate) mod spea2;
 
 pub use agemoea::AgeMoeaSurvival;
+pub use ibea::{IbeaIndicator, IbeaSurvival};
 pub use nsga2::Nsga2RankCrowdingSurvival;
 pub use nsga3::{Nsga3ReferencePoints, Nsga3ReferencePointsSurvival};
 pub use reference_points::{
-    DanAndDenisReferencePoints, NormalBoundaryDivisions, StructuredReferencePoints,
+    DanAndDenisReferencePoints, LoadedReferencePoints, NormalBoundaryDivisions,
+    ReferencePointsError, StructuredReferencePoints,
 };
 pub use revea::ReveaReferencePointsSurvival;
 pub use rnsga2::Rnsga2ReferencePointsSurvival;
@@ -43,6 +46,11 @@ pub enum SurvivalScoringComparison {
 /// For these algorithms, you only need to implement
 /// `set_front_survival_score` to compute each front’s scores;
 /// the default `operate` covers the rest.
+///
+/// If the population carries per-individual weights (see [`Population::weight`](crate::genetic::Population)),
+/// individuals of the splitting front with the same survival score (e.g. boundary points whose
+/// crowding distance is `∞`) are ordered by decreasing weight. Weights never override a
+/// difference in survival score.
 pub trait FrontsAndRankingBasedSurvival: SurvivalOperator<FDim = ndarray::Ix2> {
     /// Returns whether the survival scoring should be maximized or minimized.
     fn scoring_comparison(&self) -> SurvivalScoringComparison {
@@ -66,11 +74,32 @@ pub trait FrontsAndRankingBasedSurvival: SurvivalOperator<FDim = ndarray::Ix2> {
         num_survive: usize,
         rng: &mut impl RandomGenerator,
     ) -> PopulationMOO<ConstrDim>
+    where
+        ConstrDim: D12,
+    {
+        FrontsAndRankingBasedSurvival::operate_with_dominance_epsilon(
+            self,
+            population,
+            num_survive,
+            0.0,
+            rng,
+        )
+    }
+
+    /// Same as [`FrontsAndRankingBasedSurvival::operate`], with the fronts built using
+    /// [`fast_non_dominated_sorting_with_epsilon`](crate::non_dominated_sorting::fast_non_dominated_sorting_with_epsilon).
+    fn operate_with_dominance_epsilon<ConstrDim>(
+        &mut self,
+        population: PopulationMOO<ConstrDim>,
+        num_survive: usize,
+        dominance_epsilon: f64,
+        rng: &mut impl RandomGenerator,
+    ) -> PopulationMOO<ConstrDim>
     where
         ConstrDim: D12,
     {
         // Build fronts
-        let mut fronts = build_fronts(population, num_survive);
+        let mut fronts = build_fronts(population, num_survive, dominance_epsilon);
         // Set survival score
         self.set_front_survival_score(&mut fronts, rng);
         // Drain all fronts.
@@ -93,15 +122,24 @@ pub trait FrontsAndRankingBasedSurvival: SurvivalOperator<FDim = ndarray::Ix2> {
                         .survival_score
                         .clone()
                         .expect("No survival score set for splitting front");
+                    // Ties in the survival score are broken in favor of the higher weight.
+                    let weights = front.weights();
                     // Get indices for the current front.
                     let mut indices: Vec<usize> = (0..front_len).collect();
-                    indices.sort_by(|&i, &j| match self.scoring_comparison() {
-                        SurvivalScoringComparison::Maximize => scores[j]
-                            .partial_cmp(&scores[i])
-                            .unwrap_or(std::cmp::Ordering::Equal),
-                        SurvivalScoringComparison::Minimize => scores[i]
-                            .partial_cmp(&scores[j])
-                            .unwrap_or(std::cmp::Ordering::Equal),
+                    indices.sort_by(|&i, &j| {
+                        match self.scoring_comparison() {
+                            SurvivalScoringComparison::Maximize => scores[j]
+                                .partial_cmp(&scores[i])
+                                .unwrap_or(std::cmp::Ordering::Equal),
+                            SurvivalScoringComparison::Minimize => scores[i]
+                                .partial_cmp(&scores[j])
+                                .unwrap_or(std::cmp::Ordering::Equal),
+                        }
+                        .then_with(|| {
+                            weights[j]
+                                .partial_cmp(&weights[i])
+                                .unwrap_or(std::cmp::Ordering::Equal)
+                        })
                     });
                     // Select exactly the required number of individuals.
                     let selected_indices: Vec<usize> =
@@ -132,4 +170,23 @@ impl<T: FrontsAndRankingBasedSurvival> SurvivalOperator for T {
         // Delegate to the FrontsAndRankingBasedSurvival default implementation
         <T as FrontsAndRankingBasedSurvival>::operate(self, population, num_survive, rng)
     }
+
+    fn operate_with_dominance_epsilon<ConstrDim>(
+        &mut self,
+        population: PopulationMOO<ConstrDim>,
+        num_survive: usize,
+        dominance_epsilon: f64,
+        rng: &mut impl RandomGenerator,
+    ) -> PopulationMOO<ConstrDim>
+    where
+        ConstrDim: D12,
+    {
+        <T as FrontsAndRankingBasedSurvival>::operate_with_dominance_epsilon(
+            self,
+            population,
+            num_survive,
+            dominance_epsilon,
+            rng,
+        )
+    }
 }
@@ -130,12 +130,7 @@ mod tests {
         let distances = crowding_distance(&population_fitness);
 
         // Expected distances: the corner individuals are assigned INFINITY and the middle individual sums to 1.0.
-        let expected = array![
-            std::f64::INFINITY,
-            std::f64::INFINITY,
-            1.0,
-            std::f64::INFINITY
-        ];
+        let expected = array![f64::INFINITY, f64::INFINITY, 1.0, f64::INFINITY];
 
         assert_eq!(distances.as_slice().unwrap(), expected.as_slice().unwrap());
     }
@@ -198,12 +193,7 @@ mod tests {
         let mut rng = NoopRandomGenerator::new();
         selector.set_front_survival_score(&mut fronts, &mut rng);
 
-        let expected: Array1<f64> = array![
-            std::f64::INFINITY,
-            std::f64::INFINITY,
-            1.0,
-            std::f64::INFINITY
-        ];
+        let expected: Array1<f64> = array![f64::INFINITY, f64::INFINITY, 1.0, f64::INFINITY];
         let actual = fronts[0].survival_score.clone().unwrap();
         assert_eq!(actual.as_slice().unwrap(), expected.as_slice().unwrap());
     }
@@ -228,6 +218,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_survival_selection_dominance_epsilon() {
+        // Individuals 0 and 1 only differ by float noise, individual 2 is dominated by both.
+        let genes: Array2<f64> = array![[0.0], [1.0], [2.0]];
+        let fitness: Array2<f64> = array![[0.1, 0.9], [0.1 + 1e-12, 0.9 + 1e-12], [0.5, 1.5]];
+        let population = PopulationMOO::new_unconstrained(genes, fitness);
+        let mut selector = Nsga2RankCrowdingSurvival;
+        let mut rng = NoopRandomGenerator::new();
+
+        let survivors = selector.operate(population.clone(), 3, &mut rng);
+        assert_eq!(survivors.rank.unwrap(), array![0_usize, 1, 2]);
+
+        let survivors = selector.operate_with_dominance_epsilon(population, 3, 1e-9, &mut rng);
+        assert_eq!(survivors.rank.unwrap(), array![0_usize, 0, 1]);
+    }
+
+    #[test]
+    fn test_survival_selection_dominance_epsilon_cycle() {
+        // With epsilon = 1 individuals 0 ≻ 1 ≻ 2 ≻ 0 form a dominance cycle, all of them
+        // dominating individual 3. The cycle must still fill the survivors.
+        let genes: Array2<f64> = array![[0.0], [1.0], [2.0], [3.0]];
+        let fitness: Array2<f64> = array![
+            [0.470, 1.101, 2.060],
+            [1.598, 0.383, 1.065],
+            [0.634, 1.578, 0.572],
+            [5.0, 5.0, 5.0]
+        ];
+        let population = PopulationMOO::new_unconstrained(genes, fitness);
+        let mut selector = Nsga2RankCrowdingSurvival;
+        let mut rng = NoopRandomGenerator::new();
+
+        let survivors = selector.operate_with_dominance_epsilon(population, 2, 1.0, &mut rng);
+        assert_eq!(survivors.len(), 2);
+        assert_eq!(survivors.rank.unwrap(), array![0_usize, 0]);
+        assert!(survivors.genes.iter().all(|&g| g < 3.0));
+    }
+
     #[test]
     fn test_survival_selection_multiple_fronts() {
         /*
@@ -309,4 +336,54 @@ mod tests {
             expected_rank.as_slice().unwrap()
         );
     }
+
+    #[test]
+    fn test_survival_selection_weight_breaks_crowding_tie() {
+        // Single front evenly spaced: extremes get INFINITY and the three interior
+        // individuals get the same crowding distance (1.0). Only one interior survives.
+        let genes: Array2<f64> = array![[0.0], [1.0], [2.0], [3.0], [4.0]];
+        let fitness: Array2<f64> = array![
+            [0.0, 1.0],
+            [0.25, 0.75],
+            [0.5, 0.5],
+            [0.75, 0.25],
+            [1.0, 0.0]
+        ];
+        let mut selector = Nsga2RankCrowdingSurvival;
+        let mut rng = NoopRandomGenerator::new();
+
+        // Uniform weights: the tie is resolved in favor of the first interior individual.
+        let population = PopulationMOO::new_unconstrained(genes.clone(), fitness.clone());
+        let survivors = selector.operate(population, 3, &mut rng);
+        assert!(survivors.genes.column(0).iter().any(|&g| g == 1.0));
+        assert!(!survivors.genes.column(0).iter().any(|&g| g == 3.0));
+
+        // A higher weight on individual 3 makes it win the tie.
+        let mut population = PopulationMOO::new_unconstrained(genes, fitness);
+        population.set_weight(array![1.0, 1.0, 1.0, 5.0, 1.0]);
+        let survivors = selector.operate(population, 3, &mut rng);
+        let mut survived_genes: Vec<f64> = survivors.genes.column(0).to_vec();
+        survived_genes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(survived_genes, vec![0.0, 3.0, 4.0]);
+        let mut survived_weights = survivors.weight.unwrap().to_vec();
+        survived_weights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(survived_weights, vec![1.0, 1.0, 5.0]);
+    }
+
+    #[test]
+    fn test_survival_selection_weight_does_not_override_crowding() {
+        // Single front with distinct interior crowding distances: individual 1 is the most
+        // crowded, so it is discarded even with a much higher weight.
+        let genes: Array2<f64> = array![[0.0], [1.0], [2.0], [3.0]];
+        let fitness: Array2<f64> = array![[0.0, 1.0], [0.1, 0.9], [0.2, 0.8], [1.0, 0.0]];
+        let mut population = PopulationMOO::new_unconstrained(genes, fitness);
+        population.set_weight(array![1.0, 100.0, 1.0, 1.0]);
+        let mut selector = Nsga2RankCrowdingSurvival;
+        let mut rng = NoopRandomGenerator::new();
+
+        let survivors = selector.operate(population, 3, &mut rng);
+        let mut survived_genes: Vec<f64> = survivors.genes.column(0).to_vec();
+        survived_genes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(survived_genes, vec![0.0, 2.0, 3.0]);
+    }
 }
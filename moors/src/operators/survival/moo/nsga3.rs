@@ -94,18 +94,31 @@ impl SurvivalOperator for Nsga3ReferencePointsSurvival {
         num_survive: usize,
         rng: &mut impl RandomGenerator,
     ) -> PopulationMOO<ConstrDim>
+    where
+        ConstrDim: D12,
+    {
+        self.operate_with_dominance_epsilon(population, num_survive, 0.0, rng)
+    }
+
+    fn operate_with_dominance_epsilon<ConstrDim>(
+        &mut self,
+        population: PopulationMOO<ConstrDim>,
+        num_survive: usize,
+        dominance_epsilon: f64,
+        rng: &mut impl RandomGenerator,
+    ) -> PopulationMOO<ConstrDim>
     where
         ConstrDim: D12,
     {
         // Build fronts
-        let mut fronts = build_fronts(population, num_survive);
+        let mut fronts = build_fronts(population, num_survive, dominance_epsilon);
         // Accumulator for the merged population.
         let mut survivors: Option<PopulationMOO<ConstrDim>> = None;
         let mut n_survivors = 0;
         // Drain fronts to consume them and get an iterator of owned Population values.
-        let drained = fronts.drain(..).enumerate();
-        // Iterate over all fronts with enumerate (we no longer differentiate contexts).
-        for (_i, front) in drained {
+        let drained = fronts.drain(..);
+        // Iterate over all fronts (we no longer differentiate contexts).
+        for front in drained {
             // Save the length of the current front.
             let front_len = front.len();
 
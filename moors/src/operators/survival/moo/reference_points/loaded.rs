@@ -0,0 +1,141 @@
+use std::fs;
+use std::path::Path;
+
+use ndarray::Array2;
+
+use crate::operators::survival::moo::reference_points::{
+    ReferencePointsError, StructuredReferencePoints,
+};
+
+/// Reference points (or weight vectors) read from a file written by
+/// [`StructuredReferencePoints::save`].
+///
+/// This allows sharing the exact decomposition structure used in an experiment. Every
+/// loaded point must lie on the unit simplex: all components are non-negative and
+/// sum to one, up to [`Self::SIMPLEX_TOLERANCE`].
+#[derive(Debug, Clone)]
+pub struct LoadedReferencePoints {
+    points: Array2<f64>,
+}
+
+impl LoadedReferencePoints {
+    /// Absolute tolerance used to check that the points lie on the simplex.
+    pub const SIMPLEX_TOLERANCE: f64 = 1e-8;
+
+    /// Reads reference points from a CSV file, one point per line.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ReferencePointsError> {
+        let content = fs::read_to_string(path)?;
+        let points = parse_csv(&content)?;
+        Ok(Self { points })
+    }
+
+    /// The loaded points, one per row.
+    pub fn points(&self) -> &Array2<f64> {
+        &self.points
+    }
+}
+
+impl StructuredReferencePoints for LoadedReferencePoints {
+    fn generate(&self) -> Array2<f64> {
+        self.points.clone()
+    }
+}
+
+pub(super) fn write_csv(points: &Array2<f64>, path: &Path) -> Result<(), ReferencePointsError> {
+    let mut content = String::new();
+    for row in points.rows() {
+        let line: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+        content.push_str(&line.join(","));
+        content.push('\n');
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+fn parse_csv(content: &str) -> Result<Array2<f64>, ReferencePointsError> {
+    let mut values = Vec::new();
+    let mut num_objectives = None;
+    let mut num_points = 0;
+    for (idx, raw_line) in content.lines().enumerate() {
+        let line = idx + 1;
+        let raw_line = raw_line.trim();
+        if raw_line.is_empty() {
+            continue;
+        }
+        let point = raw_line
+            .split(',')
+            .map(|v| {
+                v.trim()
+                    .parse::<f64>()
+                    .map_err(|_| ReferencePointsError::InvalidValue {
+                        line,
+                        value: v.trim().to_string(),
+                    })
+            })
+            .collect::<Result<Vec<f64>, _>>()?;
+        let expected = *num_objectives.get_or_insert(point.len());
+        if point.len() != expected {
+            return Err(ReferencePointsError::InconsistentDimensions {
+                line,
+                expected,
+                found: point.len(),
+            });
+        }
+        let on_simplex = point
+            .iter()
+            .all(|&v| v >= -LoadedReferencePoints::SIMPLEX_TOLERANCE)
+            && (point.iter().sum::<f64>() - 1.0).abs() <= LoadedReferencePoints::SIMPLEX_TOLERANCE;
+        if !on_simplex {
+            return Err(ReferencePointsError::NotOnSimplex { line });
+        }
+        values.extend(point);
+        num_points += 1;
+    }
+    let num_objectives = num_objectives.ok_or(ReferencePointsError::Empty)?;
+    Ok(Array2::from_shape_vec((num_points, num_objectives), values)
+        .expect("Every point has the same number of objectives"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operators::survival::moo::reference_points::DanAndDenisReferencePoints;
+
+    #[test]
+    fn test_parse_csv_rejects_invalid_points() {
+        assert!(matches!(
+            parse_csv("0.5,0.5\n0.3,0.3\n"),
+            Err(ReferencePointsError::NotOnSimplex { line: 2 })
+        ));
+        assert!(matches!(
+            parse_csv("1.5,-0.5\n"),
+            Err(ReferencePointsError::NotOnSimplex { line: 1 })
+        ));
+        assert!(matches!(
+            parse_csv("0.5,0.5\n0.2,0.3,0.5\n"),
+            Err(ReferencePointsError::InconsistentDimensions {
+                line: 2,
+                expected: 2,
+                found: 3
+            })
+        ));
+        assert!(matches!(
+            parse_csv("0.5,abc\n"),
+            Err(ReferencePointsError::InvalidValue { line: 1, .. })
+        ));
+        assert!(matches!(parse_csv("\n"), Err(ReferencePointsError::Empty)));
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let reference_points = DanAndDenisReferencePoints::new(91, 3);
+        let path =
+            std::env::temp_dir().join(format!("moors_reference_points_{}.csv", std::process::id()));
+        reference_points.save(&path).expect("save failed");
+        let loaded = LoadedReferencePoints::load(&path).expect("load failed");
+        fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.points(), &reference_points.generate());
+        assert_eq!(loaded.generate(), reference_points.generate());
+    }
+}
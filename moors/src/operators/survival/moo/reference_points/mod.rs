@@ -1,11 +1,46 @@
+use std::path::Path;
+
 use ndarray::Array2;
+use thiserror::Error;
 
 mod dan_and_dennis;
+mod loaded;
 
 pub use dan_and_dennis::DanAndDenisReferencePoints;
 pub use dan_and_dennis::NormalBoundaryDivisions;
+pub use loaded::LoadedReferencePoints;
+
+/// Error type for persisting and loading reference points.
+#[derive(Debug, Error)]
+pub enum ReferencePointsError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid value '{value}' at line {line}")]
+    InvalidValue { line: usize, value: String },
+    #[error("Line {line} has {found} values, expected {expected}")]
+    InconsistentDimensions {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("No reference points found")]
+    Empty,
+    #[error("Reference point at line {line} does not lie on the unit simplex")]
+    NotOnSimplex { line: usize },
+}
 
 /// A common trait for structured reference points.
 pub trait StructuredReferencePoints {
     fn generate(&self) -> Array2<f64>;
+
+    /// Writes the generated points to `path` as CSV, one point per line.
+    ///
+    /// Values are written with their shortest round-trip representation, so loading the file
+    /// back with [`LoadedReferencePoints::load`] yields exactly the same points.
+    fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ReferencePointsError>
+    where
+        Self: Sized,
+    {
+        loaded::write_csv(&self.generate(), path.as_ref())
+    }
 }
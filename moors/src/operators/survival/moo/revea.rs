@@ -316,7 +316,7 @@ mod tests {
         // gamma_0 = min(0, 1) = 0, gamma_1 = min(0, 1) = 0, gamma_2 = min(1, 1) = 1.
         let ref_points = array![[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
         let gamma = compute_gamma(&ref_points);
-        let expected = vec![0.0, 0.0, 1.0];
+        let expected = [0.0, 0.0, 1.0];
         assert_eq!(gamma.len(), expected.len());
         for (g, e) in gamma.iter().zip(expected.iter()) {
             let diff = (g - e).abs();
@@ -333,9 +333,11 @@ mod tests {
     /// Reference Points:
     ///   - Ref1: [0.0, 1.0]
     ///   - Ref2: [1.0, 0.0]
+    ///
     /// Calculations:
     /// - For Ref1: solution 1 is closer (rank 1) and solution 2 is farther (rank 2).
     /// - For Ref2: solution 2 is closer (rank 1) and solution 1 is farther (rank 2).
+    ///
     /// Final crowding values are the minimum rank per solution:
     ///   solution1: min(1, 2) = 1, solution2: min(2, 1) = 1.
     #[test]
@@ -358,18 +360,22 @@ mod tests {
     ///   - Solution 1: [0.1, 0.9]
     ///   - Solution 2: [0.4, 0.6]
     ///   - Solution 3: [0.9, 0.1]
+    ///
     /// Reference points:
     ///   - Ref1: [0.0, 1.0]
     ///   - Ref2: [1.0, 0.0]
+    ///
     /// Calculations:
     /// For Ref1:
     ///   - Distance(solution1) ≈ 0.1414 → rank 1.
     ///   - Distance(solution2) ≈ 0.5657 → rank 2.
     ///   - Distance(solution3) ≈ 1.2728 → rank 3.
+    ///
     /// For Ref2:
     ///   - Distance(solution1) ≈ 1.2728 → rank 3.
     ///   - Distance(solution2) ≈ 0.8485 → rank 2.
     ///   - Distance(solution3) ≈ 0.1414 → rank 1.
+    ///
     /// Final crowding values:
     ///   - Solution 1: min(1, 3) = 1.
     ///   - Solution 2: min(2, 2) = 2.
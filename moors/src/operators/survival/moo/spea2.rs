@@ -5,7 +5,7 @@ use ndarray::{Array1, Array2, Axis};
 use crate::{
     genetic::{D12, PopulationMOO},
     helpers::linalg::cross_euclidean_distances_as_array,
-    non_dominated_sorting::fast_non_dominated_sorting,
+    non_dominated_sorting::fast_non_dominated_sorting_with_epsilon,
     operators::survival::SurvivalOperator,
     random::RandomGenerator,
 };
@@ -25,14 +25,12 @@ impl Default for Spea2KnnSurvival {
     }
 }
 
-impl SurvivalOperator for Spea2KnnSurvival {
-    type FDim = ndarray::Ix2;
-
-    fn operate<ConstrDim>(
-        &mut self,
+impl Spea2KnnSurvival {
+    /// Selects the survivors given the Pareto-domination index R(i) of each individual.
+    fn select_survivors<ConstrDim>(
         population: PopulationMOO<ConstrDim>,
         num_survive: usize,
-        _rng: &mut impl RandomGenerator,
+        domination_indices: Array1<f64>,
     ) -> PopulationMOO<ConstrDim>
     where
         ConstrDim: D12,
@@ -42,7 +40,6 @@ impl SurvivalOperator for Spea2KnnSurvival {
         let distance_matrix =
             cross_euclidean_distances_as_array(&population.fitness, &population.fitness);
         let density = compute_density(&distance_matrix, k);
-        let domination_indices = compute_domination_indices(&population.fitness);
         // raw_fitness[i] = domination_indices_f[i] + density[i]
         let raw_fitness: Array1<f64> = &domination_indices + &density;
         // Next step is to check out if the |{i: S(i) < 1}| = {i: raw_fitness[i] < 1}| <= num_survive
@@ -77,6 +74,38 @@ impl SurvivalOperator for Spea2KnnSurvival {
     }
 }
 
+impl SurvivalOperator for Spea2KnnSurvival {
+    type FDim = ndarray::Ix2;
+
+    fn operate<ConstrDim>(
+        &mut self,
+        population: PopulationMOO<ConstrDim>,
+        num_survive: usize,
+        _rng: &mut impl RandomGenerator,
+    ) -> PopulationMOO<ConstrDim>
+    where
+        ConstrDim: D12,
+    {
+        let domination_indices = compute_domination_indices(&population.fitness);
+        Self::select_survivors(population, num_survive, domination_indices)
+    }
+
+    fn operate_with_dominance_epsilon<ConstrDim>(
+        &mut self,
+        population: PopulationMOO<ConstrDim>,
+        num_survive: usize,
+        dominance_epsilon: f64,
+        _rng: &mut impl RandomGenerator,
+    ) -> PopulationMOO<ConstrDim>
+    where
+        ConstrDim: D12,
+    {
+        let domination_indices =
+            compute_domination_indices_with_epsilon(&population.fitness, dominance_epsilon);
+        Self::select_survivors(population, num_survive, domination_indices)
+    }
+}
+
 /// Compute density D(i) = 1 / (σᵢᵏ + 2) for each individual i,
 /// where σᵢᵏ is the k-th smallest distance in row i (including the zero at index 0).
 ///
@@ -116,8 +145,17 @@ pub fn compute_density(distance_matrix: &Array2<f64>, k: usize) -> Array1<f64> {
 /// all individuals into successive non-dominated sets, then assigns each
 /// individual the index of the set it belongs to.
 pub fn compute_domination_indices(population_fitness: &Array2<f64>) -> Array1<f64> {
+    compute_domination_indices_with_epsilon(population_fitness, 0.0)
+}
+
+/// Same as [`compute_domination_indices`], with the sets built by
+/// `fast_non_dominated_sorting_with_epsilon(..., N, dominance_epsilon)`.
+pub fn compute_domination_indices_with_epsilon(
+    population_fitness: &Array2<f64>,
+    dominance_epsilon: f64,
+) -> Array1<f64> {
     let n = population_fitness.nrows();
-    let ranks = fast_non_dominated_sorting(population_fitness, n);
+    let ranks = fast_non_dominated_sorting_with_epsilon(population_fitness, n, dominance_epsilon);
 
     let mut domination_indices = Array1::<f64>::zeros(n);
 
@@ -270,6 +308,26 @@ mod tests {
         assert_eq!(indices, array![0.0, 1.0, 2.0]);
     }
 
+    #[test]
+    fn test_domination_indices_with_epsilon_cycle() {
+        // With epsilon = 1 individuals 0 ≻ 1 ≻ 2 ≻ 0 form a dominance cycle and all of them
+        // dominate individual 3, which must not share their index.
+        let fitness = array![
+            [0.470, 1.101, 2.060],
+            [1.598, 0.383, 1.065],
+            [0.634, 1.578, 0.572],
+            [5.0, 5.0, 5.0]
+        ];
+        let indices = compute_domination_indices_with_epsilon(&fitness, 1.0);
+        assert_eq!(indices, array![0.0, 0.0, 0.0, 1.0]);
+
+        let pop = make_population(fitness);
+        let mut rng = NoopRandomGenerator::new();
+        let survivors = Spea2KnnSurvival.operate_with_dominance_epsilon(pop, 3, 1.0, &mut rng);
+        assert_eq!(survivors.len(), 3);
+        assert!(survivors.fitness.iter().all(|&f| f < 5.0));
+    }
+
     #[test]
     fn test_no_dominance_all_zero() {
         // All are non-dominated pairwise: rank 0 for everyone
@@ -0,0 +1,221 @@
+use std::cmp::Ordering;
+
+use ndarray::Array1;
+
+use crate::{
+    genetic::{D12, PopulationSOO},
+    operators::survival::SurvivalOperator,
+    random::RandomGenerator,
+};
+
+/// A survival operator that selects individuals based on a **penalized fitness** score,
+/// like [`FitnessConstraintsPenaltySurvival`](super::FitnessConstraintsPenaltySurvival), but
+/// with a penalty factor that **adapts itself** every generation.
+///
+/// The selection score for each individual is computed as:
+///
+/// ```text
+/// penalized_score = fitness + constraints_penalty × constraint_violation
+/// ```
+///
+/// Before ranking, the fraction of feasible individuals in the incoming population is
+/// compared with [`Self::TARGET_FEASIBLE_RATIO`]:
+///
+/// - If the population is mostly infeasible, the penalty is multiplied by `1 + adaptation_rate`.
+/// - If the population is mostly feasible, the penalty is divided by `1 + adaptation_rate`.
+///
+/// This way the penalty grows until the search is pushed into the feasible region and shrinks
+/// again to let the search explore solutions close to the constraints boundary, removing the
+/// need to hand tune a fixed factor.
+///
+/// If no constraint violations are present in the population, selection defaults
+/// to pure fitness-based minimization and the penalty is left untouched.
+#[derive(Debug, Clone)]
+pub struct AdaptiveConstraintsPenaltySurvival {
+    constraints_penalty: f64,
+    adaptation_rate: f64,
+}
+
+impl AdaptiveConstraintsPenaltySurvival {
+    /// Fraction of feasible individuals that separates "mostly infeasible" from "mostly feasible".
+    pub const TARGET_FEASIBLE_RATIO: f64 = 0.5;
+
+    /// * `initial_penalty` – penalty factor used in the first generation, must be positive.
+    /// * `adaptation_rate` – relative change applied to the penalty each generation, must be positive.
+    pub fn new(initial_penalty: f64, adaptation_rate: f64) -> Self {
+        assert!(
+            initial_penalty > 0.0,
+            "AdaptiveConstraintsPenaltySurvival: initial_penalty must be greater than 0"
+        );
+        assert!(
+            adaptation_rate > 0.0,
+            "AdaptiveConstraintsPenaltySurvival: adaptation_rate must be greater than 0"
+        );
+        Self {
+            constraints_penalty: initial_penalty,
+            adaptation_rate,
+        }
+    }
+
+    /// Current value of the penalty factor.
+    pub fn constraints_penalty(&self) -> f64 {
+        self.constraints_penalty
+    }
+
+    pub fn adaptation_rate(&self) -> f64 {
+        self.adaptation_rate
+    }
+
+    /// Updates the penalty factor from the fraction of feasible individuals.
+    fn adapt(&mut self, violations: &Array1<f64>) {
+        if violations.is_empty() {
+            return;
+        }
+        let num_feasible = violations.iter().filter(|&&v| v == 0.0).count();
+        let feasible_ratio = num_feasible as f64 / violations.len() as f64;
+        let factor = 1.0 + self.adaptation_rate;
+        if feasible_ratio < Self::TARGET_FEASIBLE_RATIO {
+            self.constraints_penalty *= factor;
+        } else if feasible_ratio > Self::TARGET_FEASIBLE_RATIO {
+            self.constraints_penalty /= factor;
+        }
+    }
+}
+
+impl SurvivalOperator for AdaptiveConstraintsPenaltySurvival {
+    type FDim = ndarray::Ix1;
+
+    fn operate<ConstrDim>(
+        &mut self,
+        population: PopulationSOO<ConstrDim>,
+        num_survive: usize,
+        _rng: &mut impl RandomGenerator,
+    ) -> PopulationSOO<ConstrDim>
+    where
+        ConstrDim: D12,
+    {
+        let pop_size = population.fitness.len();
+        let mut indices: Vec<usize> = (0..pop_size).collect();
+
+        if let Some(violations) = &population.constraint_violation_totals {
+            self.adapt(violations);
+            let penalty_scores: Vec<f64> = (0..pop_size)
+                .map(|i| self.constraints_penalty * violations[i] + population.fitness[i])
+                .collect();
+
+            indices.sort_by(|&i, &j| {
+                penalty_scores[i]
+                    .partial_cmp(&penalty_scores[j])
+                    .unwrap_or(Ordering::Equal)
+            });
+        } else {
+            indices.sort_by(|&i, &j| {
+                population.fitness[i]
+                    .partial_cmp(&population.fitness[j])
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+
+        let survive_count = num_survive.min(pop_size);
+        let selected_indices = &indices[..survive_count];
+        let mut selected_population = population.selected(selected_indices);
+
+        selected_population.set_rank(Array1::from_iter(0..survive_count));
+        selected_population
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::random::NoopRandomGenerator;
+    use ndarray::{Array2, array};
+
+    #[test]
+    fn penalty_changes_across_generations() {
+        let mut rng = NoopRandomGenerator::new();
+        let mut selector = AdaptiveConstraintsPenaltySurvival::new(1.0, 0.5);
+
+        // Generation 1: mostly infeasible → penalty increases
+        let genes = Array2::zeros((4, 1));
+        let pop = PopulationSOO::new(
+            genes.clone(),
+            array![0.1, 0.2, 0.3, 0.4],
+            array![5.0, 5.0, 5.0, 0.0],
+        );
+        selector.operate(pop, 2, &mut rng);
+        assert!((selector.constraints_penalty() - 1.5).abs() < 1e-12);
+
+        // Generation 2: still mostly infeasible → penalty increases again
+        let pop = PopulationSOO::new(
+            genes.clone(),
+            array![0.1, 0.2, 0.3, 0.4],
+            array![5.0, 0.0, 5.0, 5.0],
+        );
+        selector.operate(pop, 2, &mut rng);
+        assert!((selector.constraints_penalty() - 2.25).abs() < 1e-12);
+
+        // Generation 3: exactly half feasible → penalty is kept
+        let pop = PopulationSOO::new(
+            genes.clone(),
+            array![0.1, 0.2, 0.3, 0.4],
+            array![5.0, 5.0, 0.0, 0.0],
+        );
+        selector.operate(pop, 2, &mut rng);
+        assert!((selector.constraints_penalty() - 2.25).abs() < 1e-12);
+
+        // Generation 4: all feasible → penalty decreases
+        let pop = PopulationSOO::new(
+            genes,
+            array![0.1, 0.2, 0.3, 0.4],
+            array![0.0, 0.0, 0.0, 0.0],
+        );
+        selector.operate(pop, 2, &mut rng);
+        assert!((selector.constraints_penalty() - 1.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn adapted_penalty_affects_selection_order() {
+        // Individual 0: great fitness, high violation
+        // Individual 1: poor fitness, low violation
+        // Individual 2 and 3 are infeasible too, so the penalty increases each generation.
+        let genes = Array2::zeros((4, 1));
+        let fitness = array![0.1, 0.9, 5.0, 5.0];
+        let constraints = array![1.0, 0.1, 10.0, 10.0];
+        let pop = PopulationSOO::new(genes, fitness, constraints);
+        let mut rng = NoopRandomGenerator::new();
+        let mut selector = AdaptiveConstraintsPenaltySurvival::new(0.1, 1.0);
+
+        // penalty = 0.2: 0.1 + 0.2 * 1.0 = 0.3 < 0.9 + 0.2 * 0.1 = 0.92
+        let survived = selector.operate(pop.clone(), 1, &mut rng);
+        assert_eq!(survived.fitness, array![0.1]);
+        // penalty = 0.4: 0.5 < 0.94
+        selector.operate(pop.clone(), 1, &mut rng);
+        // penalty = 0.8: 0.9 < 0.98
+        selector.operate(pop.clone(), 1, &mut rng);
+        // penalty = 1.6: 1.7 > 1.06 → violation now dominates
+        let survived = selector.operate(pop, 1, &mut rng);
+        assert!((selector.constraints_penalty() - 1.6).abs() < 1e-12);
+        assert_eq!(survived.fitness, array![0.9]);
+        assert_eq!(survived.rank.unwrap(), array![0]);
+    }
+
+    #[test]
+    fn unconstrained_population_keeps_penalty() {
+        let genes = Array2::zeros((3, 1));
+        let fitness = array![0.3, 0.1, 0.2];
+        let pop = PopulationSOO::new_unconstrained(genes, fitness);
+        let mut rng = NoopRandomGenerator::new();
+        let mut selector = AdaptiveConstraintsPenaltySurvival::new(2.0, 0.1);
+        let survived = selector.operate(pop, 2, &mut rng);
+        assert_eq!(survived.fitness, array![0.1, 0.2]);
+        assert_eq!(selector.constraints_penalty(), 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "adaptation_rate must be greater than 0")]
+    fn non_positive_adaptation_rate_panics() {
+        AdaptiveConstraintsPenaltySurvival::new(1.0, 0.0);
+    }
+}
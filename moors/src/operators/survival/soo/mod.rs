@@ -1,5 +1,7 @@
+pub(crate) mod adaptive_constraints_penalty;
 pub(crate) mod fitness;
 pub(crate) mod fitness_constraints_penalty;
 
+pub use adaptive_constraints_penalty::AdaptiveConstraintsPenaltySurvival;
 pub use fitness::FitnessSurvival;
 pub use fitness_constraints_penalty::FitnessConstraintsPenaltySurvival;
@@ -0,0 +1,360 @@
+//! Conformance checks for user defined operators.
+//!
+//! [`validate_operator`] runs a sampler, crossover or mutation operator against synthetic
+//! inputs and checks the contracts the rest of the pipeline relies on:
+//!
+//! * **Shape** – samplers return `population_size × num_vars` genes, crossovers return
+//!   `n_offsprings_per_crossover × population_size` offspring with `num_vars` genes and
+//!   mutations keep the population shape.
+//! * **Bounds** – if the context has bounds, every output gene lies within them (inputs are
+//!   sampled within the bounds too). All genes must be finite.
+//! * **Determinism** – two runs started from clones of the same RNG produce the same output.
+//!
+//! Crossovers are checked in two steps: every pair of synthetic parents is crossed with
+//! [`CrossoverOperator::crossover`] and each child must have the length of its parents, then the
+//! offspring of [`CrossoverOperator::operate`], the pipeline entry point, must have the expected
+//! shape. Children of the wrong length are therefore reported as
+//! [`OperatorValidationError::WrongShape`] before `operate` could panic on them.
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use moors::{
+//!     operators::{SBXCrossover, validation::{OperatorValidationContext, validate_operator}},
+//!     random::MOORandomGenerator,
+//! };
+//!
+//! let sbx = SBXCrossover::new(15.0, Arc::new(vec![(0.0, 1.0); 5]));
+//! let context = OperatorValidationContext::new(5, 20).with_bounds(0.0, 1.0);
+//! let rng = MOORandomGenerator::new_from_seed(Some(42));
+//! assert!(validate_operator(&sbx, &context, &rng).is_ok());
+//! ```
+use ndarray::Array2;
+use thiserror::Error;
+
+use crate::{
+    operators::{CrossoverOperator, MutationOperator, SamplingOperator},
+    random::{MOORandomGenerator, RandomGenerator},
+};
+
+/// Seed of the RNG generating the synthetic inputs (parents, individuals to mutate).
+const SYNTHETIC_INPUT_SEED: u64 = 0;
+
+/// Problem dimensions and bounds used to validate an operator.
+#[derive(Debug, Clone)]
+pub struct OperatorValidationContext {
+    pub num_vars: usize,
+    pub population_size: usize,
+    pub lower_bound: Option<f64>,
+    pub upper_bound: Option<f64>,
+}
+
+impl OperatorValidationContext {
+    pub fn new(num_vars: usize, population_size: usize) -> Self {
+        Self {
+            num_vars,
+            population_size,
+            lower_bound: None,
+            upper_bound: None,
+        }
+    }
+
+    /// Requires every gene of the inputs and outputs to lie in `[lower_bound, upper_bound]`.
+    pub fn with_bounds(mut self, lower_bound: f64, upper_bound: f64) -> Self {
+        self.lower_bound = Some(lower_bound);
+        self.upper_bound = Some(upper_bound);
+        self
+    }
+}
+
+/// A contract violated by the validated operator.
+#[derive(Debug, Error, PartialEq)]
+pub enum OperatorValidationError {
+    #[error("Wrong output shape: expected {expected:?}, got {found:?}")]
+    WrongShape {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    #[error("Gene {value} at ({row}, {col}) is out of bounds [{lower}, {upper}]")]
+    OutOfBounds {
+        row: usize,
+        col: usize,
+        value: f64,
+        lower: f64,
+        upper: f64,
+    },
+    #[error("Gene at ({row}, {col}) is not finite")]
+    NonFinite { row: usize, col: usize },
+    #[error("Two runs with the same seed produced different outputs")]
+    NonDeterministic,
+}
+
+/// Marker for [`SamplingOperator`] implementors, see [`ValidateOperator`].
+pub struct SamplerKind;
+/// Marker for [`CrossoverOperator`] implementors, see [`ValidateOperator`].
+pub struct CrossoverKind;
+/// Marker for [`MutationOperator`] implementors, see [`ValidateOperator`].
+pub struct MutationKind;
+
+/// Runs an operator on synthetic inputs. Implemented for every sampler, crossover and mutation
+/// operator; the `Kind` marker only tells the blanket implementations apart and is inferred.
+pub trait ValidateOperator<Kind> {
+    /// Runs the operator once and returns its output genes, checking their shape.
+    fn run_synthetic<R: RandomGenerator>(
+        &self,
+        context: &OperatorValidationContext,
+        rng: &mut R,
+    ) -> Result<Array2<f64>, OperatorValidationError>;
+}
+
+impl<T: SamplingOperator> ValidateOperator<SamplerKind> for T {
+    fn run_synthetic<R: RandomGenerator>(
+        &self,
+        context: &OperatorValidationContext,
+        rng: &mut R,
+    ) -> Result<Array2<f64>, OperatorValidationError> {
+        let output = self.operate(context.population_size, context.num_vars, rng);
+        check_shape(output, (context.population_size, context.num_vars))
+    }
+}
+
+impl<T: CrossoverOperator> ValidateOperator<CrossoverKind> for T {
+    fn run_synthetic<R: RandomGenerator>(
+        &self,
+        context: &OperatorValidationContext,
+        rng: &mut R,
+    ) -> Result<Array2<f64>, OperatorValidationError> {
+        let mut input_rng = MOORandomGenerator::new_from_seed(Some(SYNTHETIC_INPUT_SEED));
+        let parents_a = synthetic_genes(context, &mut input_rng);
+        let parents_b = synthetic_genes(context, &mut input_rng);
+        let expected = (
+            self.n_offsprings_per_crossover() * context.population_size,
+            context.num_vars,
+        );
+        // Check every child first: the pipeline entry point panics on children of the wrong
+        // length. The pairs are crossed with the input RNG so `rng` drives `operate` alone.
+        for (parent_a, parent_b) in parents_a.rows().into_iter().zip(parents_b.rows()) {
+            let (child_a, child_b) =
+                self.crossover(&parent_a.to_owned(), &parent_b.to_owned(), &mut input_rng);
+            for child in [child_a, child_b] {
+                if child.len() != context.num_vars {
+                    return Err(OperatorValidationError::WrongShape {
+                        expected,
+                        found: (expected.0, child.len()),
+                    });
+                }
+            }
+        }
+        let output = self.operate(&parents_a, &parents_b, 1.0, rng);
+        check_shape(output, expected)
+    }
+}
+
+impl<T: MutationOperator> ValidateOperator<MutationKind> for T {
+    fn run_synthetic<R: RandomGenerator>(
+        &self,
+        context: &OperatorValidationContext,
+        rng: &mut R,
+    ) -> Result<Array2<f64>, OperatorValidationError> {
+        let mut input_rng = MOORandomGenerator::new_from_seed(Some(SYNTHETIC_INPUT_SEED));
+        let mut population = synthetic_genes(context, &mut input_rng);
+        self.operate(&mut population, 1.0, rng);
+        check_shape(population, (context.population_size, context.num_vars))
+    }
+}
+
+fn check_shape(
+    output: Array2<f64>,
+    expected: (usize, usize),
+) -> Result<Array2<f64>, OperatorValidationError> {
+    if output.dim() != expected {
+        return Err(OperatorValidationError::WrongShape {
+            expected,
+            found: output.dim(),
+        });
+    }
+    Ok(output)
+}
+
+/// Genes sampled uniformly within the context bounds (`[0, 1)` when unbounded).
+fn synthetic_genes(
+    context: &OperatorValidationContext,
+    rng: &mut impl RandomGenerator,
+) -> Array2<f64> {
+    let lower = context.lower_bound.unwrap_or(0.0);
+    let upper = context.upper_bound.unwrap_or(lower + 1.0);
+    Array2::from_shape_simple_fn((context.population_size, context.num_vars), || {
+        rng.gen_range_f64(lower, upper)
+    })
+}
+
+/// Checks that `operator` honors the pipeline contracts (see the [module docs](self)).
+///
+/// The operator is run twice, each time with a clone of `rng`, so the outcome does not depend
+/// on the state of `rng` after the call.
+pub fn validate_operator<Kind, O, R>(
+    operator: &O,
+    context: &OperatorValidationContext,
+    rng: &R,
+) -> Result<(), OperatorValidationError>
+where
+    O: ValidateOperator<Kind>,
+    R: RandomGenerator + Clone,
+{
+    let output = operator.run_synthetic(context, &mut rng.clone())?;
+    for ((row, col), &value) in output.indexed_iter() {
+        if !value.is_finite() {
+            return Err(OperatorValidationError::NonFinite { row, col });
+        }
+        let lower = context.lower_bound.unwrap_or(f64::NEG_INFINITY);
+        let upper = context.upper_bound.unwrap_or(f64::INFINITY);
+        if value < lower || value > upper {
+            return Err(OperatorValidationError::OutOfBounds {
+                row,
+                col,
+                value,
+                lower,
+                upper,
+            });
+        }
+    }
+    let second_output = operator.run_synthetic(context, &mut rng.clone())?;
+    if second_output != output {
+        return Err(OperatorValidationError::NonDeterministic);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::sync::Arc;
+
+    use ndarray::{Array1, ArrayViewMut1};
+
+    use crate::operators::{
+        GaussianMutation, RandomSamplingFloat, SBXCrossover, SimulatedBinaryCrossover,
+    };
+
+    /// Crossover returning children with one gene less.
+    struct BrokenCrossover;
+
+    impl CrossoverOperator for BrokenCrossover {
+        fn crossover(
+            &self,
+            parent_a: &Array1<f64>,
+            parent_b: &Array1<f64>,
+            _rng: &mut impl RandomGenerator,
+        ) -> (Array1<f64>, Array1<f64>) {
+            let n = parent_a.len() - 1;
+            (
+                parent_a.slice(ndarray::s![..n]).to_owned(),
+                parent_b.slice(ndarray::s![..n]).to_owned(),
+            )
+        }
+    }
+
+    /// Crossover whose `operate` drops the last offspring, although `crossover` is fine.
+    struct TruncatingCrossover;
+
+    impl CrossoverOperator for TruncatingCrossover {
+        fn crossover(
+            &self,
+            parent_a: &Array1<f64>,
+            parent_b: &Array1<f64>,
+            _rng: &mut impl RandomGenerator,
+        ) -> (Array1<f64>, Array1<f64>) {
+            (parent_a.clone(), parent_b.clone())
+        }
+
+        fn operate(
+            &self,
+            parents_a: &Array2<f64>,
+            parents_b: &Array2<f64>,
+            _crossover_rate: f64,
+            _rng: &mut impl RandomGenerator,
+        ) -> Array2<f64> {
+            let offspring =
+                ndarray::concatenate(ndarray::Axis(0), &[parents_a.view(), parents_b.view()])
+                    .unwrap();
+            offspring.slice(ndarray::s![..-1, ..]).to_owned()
+        }
+    }
+
+    /// Crossover that keeps the shape but pushes genes out of the bounds.
+    struct OutOfBoundsCrossover;
+
+    impl CrossoverOperator for OutOfBoundsCrossover {
+        fn crossover(
+            &self,
+            parent_a: &Array1<f64>,
+            parent_b: &Array1<f64>,
+            _rng: &mut impl RandomGenerator,
+        ) -> (Array1<f64>, Array1<f64>) {
+            (parent_a + 2.0, parent_b.clone())
+        }
+    }
+
+    /// Mutation depending on hidden state instead of the RNG.
+    struct StatefulMutation {
+        calls: Cell<usize>,
+    }
+
+    impl MutationOperator for StatefulMutation {
+        fn mutate<'a>(
+            &self,
+            mut individual: ArrayViewMut1<'a, f64>,
+            _rng: &mut impl RandomGenerator,
+        ) {
+            self.calls.set(self.calls.get() + 1);
+            individual[0] = self.calls.get() as f64 / 1000.0;
+        }
+    }
+
+    #[test]
+    fn test_shipped_operators_pass() {
+        let context = OperatorValidationContext::new(5, 20).with_bounds(0.0, 1.0);
+        let rng = MOORandomGenerator::new_from_seed(Some(42));
+        let sbx = SBXCrossover::new(15.0, Arc::new(vec![(0.0, 1.0); 5]));
+        assert_eq!(validate_operator(&sbx, &context, &rng), Ok(()));
+        let sampler = RandomSamplingFloat::new(0.0, 1.0);
+        assert_eq!(validate_operator(&sampler, &context, &rng), Ok(()));
+        // The unbounded SBX and gaussian mutation rely on the pipeline clamping the genes.
+        let context = OperatorValidationContext::new(5, 20);
+        let sbx = SimulatedBinaryCrossover::new(15.0);
+        assert_eq!(validate_operator(&sbx, &context, &rng), Ok(()));
+        let mutation = GaussianMutation::new(0.5, 0.1);
+        assert_eq!(validate_operator(&mutation, &context, &rng), Ok(()));
+    }
+
+    #[test]
+    fn test_broken_operators_fail() {
+        let context = OperatorValidationContext::new(5, 20).with_bounds(0.0, 1.0);
+        let rng = MOORandomGenerator::new_from_seed(Some(42));
+        assert_eq!(
+            validate_operator(&BrokenCrossover, &context, &rng),
+            Err(OperatorValidationError::WrongShape {
+                expected: (40, 5),
+                found: (40, 4)
+            })
+        );
+        assert_eq!(
+            validate_operator(&TruncatingCrossover, &context, &rng),
+            Err(OperatorValidationError::WrongShape {
+                expected: (40, 5),
+                found: (39, 5)
+            })
+        );
+        assert!(matches!(
+            validate_operator(&OutOfBoundsCrossover, &context, &rng),
+            Err(OperatorValidationError::OutOfBounds { row: 0, .. })
+        ));
+        let mutation = StatefulMutation {
+            calls: Cell::new(0),
+        };
+        assert_eq!(
+            validate_operator(&mutation, &context, &rng),
+            Err(OperatorValidationError::NonDeterministic)
+        );
+    }
+}
@@ -44,6 +44,18 @@
 //! }
 //! ```
 //!
+//! ## Entropy-free environments
+//!
+//! When no seed is given, [`MOORandomGenerator::new_from_seed`] seeds itself from the
+//! operating system and panics if system entropy is unavailable, as in some sandboxes
+//! (CI runners, WASM hosts, jailed containers). Pass an explicit seed there, or use
+//! [`MOORandomGenerator::try_from_entropy_source`] to handle the error.
+//!
+//! With the `entropy-fallback` feature, the generator instead falls back to the fixed
+//! `ENTROPY_FALLBACK_SEED` and prints a warning to `stderr`
+//! (`MOORandomGenerator::from_entropy_source_or_fallback` returns the warning instead).
+//! Runs in such environments are then reproducible, but **not** random across runs.
+//!
 //! ## Why not use `rand` directly everywhere?
 //!
 //! 1. **Centralised helpers** keep operator code concise and consistent.
@@ -54,9 +66,26 @@
 //! ---
 //!
 use rand::prelude::IndexedRandom;
-use rand::rngs::StdRng;
+use rand::rngs::{OsRng, StdRng};
 use rand::seq::SliceRandom;
-use rand::{Rng, RngCore, SeedableRng};
+use rand::{Rng, RngCore, SeedableRng, TryRngCore};
+#[cfg(feature = "entropy-fallback")]
+use thiserror::Error;
+
+/// Seed used by [`MOORandomGenerator`] when no seed is given and the system entropy
+/// source is unavailable.
+#[cfg(feature = "entropy-fallback")]
+pub const ENTROPY_FALLBACK_SEED: u64 = 42;
+
+/// Warning returned when the entropy source failed and [`ENTROPY_FALLBACK_SEED`] was used.
+#[cfg(feature = "entropy-fallback")]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error(
+    "system entropy unavailable ({reason}), falling back to the fixed seed {ENTROPY_FALLBACK_SEED}"
+)]
+pub struct EntropyFallbackWarning {
+    pub reason: String,
+}
 
 /// A trait defining a unified interface for generating random values,
 /// used across genetic operators and algorithms.
@@ -112,9 +141,53 @@ impl MOORandomGenerator {
     pub fn new(rng: StdRng) -> Self {
         Self { rng }
     }
+    /// Creates a new `MOORandomGenerator` from `seed`, or from system entropy if `seed` is `None`.
+    ///
+    /// # Panics
+    /// Panics if `seed` is `None` and system entropy is unavailable, unless the
+    /// `entropy-fallback` feature is enabled: the generator then falls back to
+    /// `ENTROPY_FALLBACK_SEED` and prints a warning to `stderr`.
     pub fn new_from_seed(seed: Option<u64>) -> Self {
-        let rng = seed.map_or_else(|| StdRng::from_rng(&mut rand::rng()), StdRng::seed_from_u64);
-        Self { rng }
+        match seed {
+            Some(seed) => Self::new(StdRng::seed_from_u64(seed)),
+            #[cfg(feature = "entropy-fallback")]
+            None => {
+                let (rng, warning) = Self::from_entropy_source_or_fallback(&mut OsRng);
+                if let Some(warning) = warning {
+                    eprintln!("moors warning: {warning}");
+                }
+                rng
+            }
+            #[cfg(not(feature = "entropy-fallback"))]
+            None => Self::try_from_entropy_source(&mut OsRng).unwrap_or_else(|err| {
+                panic!(
+                    "System entropy unavailable ({err}): pass an explicit seed or enable the \
+                     `entropy-fallback` feature"
+                )
+            }),
+        }
+    }
+
+    /// Seeds a new `MOORandomGenerator` from the given entropy `source`.
+    pub fn try_from_entropy_source<S: TryRngCore>(source: &mut S) -> Result<Self, S::Error> {
+        StdRng::try_from_rng(source).map(Self::new)
+    }
+
+    /// Seeds a new `MOORandomGenerator` from the given entropy `source`. If the source fails,
+    /// the generator is seeded with [`ENTROPY_FALLBACK_SEED`] and the returned warning says so.
+    #[cfg(feature = "entropy-fallback")]
+    pub fn from_entropy_source_or_fallback<S: TryRngCore>(
+        source: &mut S,
+    ) -> (Self, Option<EntropyFallbackWarning>) {
+        match Self::try_from_entropy_source(source) {
+            Ok(rng) => (rng, None),
+            Err(err) => (
+                Self::new_from_seed(Some(ENTROPY_FALLBACK_SEED)),
+                Some(EntropyFallbackWarning {
+                    reason: err.to_string(),
+                }),
+            ),
+        }
     }
 }
 
@@ -232,6 +305,65 @@ mod tests {
         assert!(!rng.gen_bool(0.0), "gen_bool(0.0) did not return false");
     }
 
+    /// Entropy source that always fails, as in a sandbox without access to system entropy.
+    struct FailingEntropySource;
+
+    #[derive(Debug)]
+    struct EntropyUnavailable;
+
+    impl std::fmt::Display for EntropyUnavailable {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "entropy unavailable")
+        }
+    }
+
+    impl std::error::Error for EntropyUnavailable {}
+
+    impl TryRngCore for FailingEntropySource {
+        type Error = EntropyUnavailable;
+
+        fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+            Err(EntropyUnavailable)
+        }
+
+        fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+            Err(EntropyUnavailable)
+        }
+
+        fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Self::Error> {
+            Err(EntropyUnavailable)
+        }
+    }
+
+    #[test]
+    fn test_entropy_failure_is_an_error() {
+        let result = MOORandomGenerator::try_from_entropy_source(&mut FailingEntropySource);
+        assert!(result.is_err());
+        let mut source = StdRng::seed_from_u64(7);
+        assert!(MOORandomGenerator::try_from_entropy_source(&mut source).is_ok());
+    }
+
+    #[cfg(feature = "entropy-fallback")]
+    #[test]
+    fn test_entropy_failure_falls_back_to_fixed_seed_with_warning() {
+        let (mut rng, warning) =
+            MOORandomGenerator::from_entropy_source_or_fallback(&mut FailingEntropySource);
+        assert_eq!(
+            warning,
+            Some(EntropyFallbackWarning {
+                reason: "entropy unavailable".to_string()
+            })
+        );
+        let mut expected = MOORandomGenerator::new_from_seed(Some(ENTROPY_FALLBACK_SEED));
+        for _ in 0..10 {
+            assert_eq!(rng.rng().next_u64(), expected.rng().next_u64());
+        }
+
+        let mut source = StdRng::seed_from_u64(7);
+        let (_, warning) = MOORandomGenerator::from_entropy_source_or_fallback(&mut source);
+        assert_eq!(warning, None);
+    }
+
     #[test]
     fn test_gen_probability() {
         let seed = [42u8; 32];
@@ -240,7 +372,7 @@ mod tests {
         // gen_probability (note the method name) returns a f64 in the range [0, 1).
         let prob = rng.gen_probability();
         assert!(
-            prob >= 0.0 && prob < 1.0,
+            (0.0..1.0).contains(&prob),
             "gen_probability produced {} which is not in [0, 1)",
             prob
         );
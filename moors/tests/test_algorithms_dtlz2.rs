@@ -2,7 +2,7 @@ use ndarray::{Array2, Axis, stack};
 
 use moors::{
     DanAndDenisReferencePoints,
-    algorithms::{Nsga3Builder, ReveaBuilder},
+    algorithms::{IbeaBuilder, Nsga2Builder, Nsga3Builder, ReveaBuilder},
     duplicates::CloseDuplicatesCleaner,
     genetic::PopulationMOO,
     impl_constraints_fn,
@@ -37,6 +37,26 @@ fn fitness_dtlz2_3obj(pop: &Array2<f64>) -> Array2<f64> {
     stack(Axis(1), &[f1.view(), f2.view(), f3.view()]).expect("stack failed")
 }
 
+/// Inverted generational distance: mean distance from each point of the true
+/// front (Das-Dennis points projected onto the unit sphere) to the closest
+/// individual of the population
+fn igd_unit_sphere(pop: &PopulationMOO) -> f64 {
+    let reference = DanAndDenisReferencePoints::new(100, 3).generate();
+    let distances: Vec<f64> = reference
+        .rows()
+        .into_iter()
+        .map(|r| {
+            let target = &r / r.dot(&r).sqrt();
+            pop.fitness
+                .rows()
+                .into_iter()
+                .map(|f| (&f - &target).mapv(|d| d * d).sum().sqrt())
+                .fold(f64::INFINITY, f64::min)
+        })
+        .collect();
+    distances.iter().sum::<f64>() / distances.len() as f64
+}
+
 /// Common assertion: the Pareto front must include the entire population
 /// and each objective vector must lie on the unit sphere f1² + f2² + f3² = 1
 fn assert_full_unit_sphere(pop: &PopulationMOO) {
@@ -94,7 +114,7 @@ fn test_nsga3_dtlz2_three_objectives() {
     let population = algorithm
         .population()
         .expect("population should have been initialized");
-    assert_full_unit_sphere(&population);
+    assert_full_unit_sphere(population);
 }
 
 #[test]
@@ -132,5 +152,66 @@ fn test_revea_dtlz2_three_objectives() {
     let population = algorithm
         .population()
         .expect("population should have been initialized");
-    assert_full_unit_sphere(&population);
+    assert_full_unit_sphere(population);
+}
+
+#[test]
+fn test_ibea_dtlz2_three_objectives_competitive_with_nsga2() {
+    impl_constraints_fn!(MyConstr, lower_bound = 0.0, upper_bound = 1.0);
+
+    let mut ibea = IbeaBuilder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(20.0))
+        .mutation(GaussianMutation::new(0.05, 0.1))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_dtlz2_3obj)
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(100)
+        .num_offsprings(100)
+        .num_iterations(200)
+        .mutation_rate(0.05)
+        .crossover_rate(0.9)
+        .keep_infeasible(false)
+        .verbose(false)
+        .seed(123)
+        .build()
+        .expect("failed to build IBEA");
+    ibea.run().expect("IBEA run failed");
+
+    let mut nsga2 = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(20.0))
+        .mutation(GaussianMutation::new(0.05, 0.1))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_dtlz2_3obj)
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(100)
+        .num_offsprings(100)
+        .num_iterations(200)
+        .mutation_rate(0.05)
+        .crossover_rate(0.9)
+        .keep_infeasible(false)
+        .verbose(false)
+        .seed(123)
+        .build()
+        .expect("failed to build NSGA2");
+    nsga2.run().expect("NSGA2 run failed");
+
+    let ibea_population = ibea
+        .population()
+        .expect("population should have been initialized");
+    assert_full_unit_sphere(ibea_population);
+
+    let ibea_igd = igd_unit_sphere(ibea_population);
+    let nsga2_igd = igd_unit_sphere(
+        nsga2
+            .population()
+            .expect("population should have been initialized"),
+    );
+    assert!(
+        ibea_igd <= 1.2 * nsga2_igd,
+        "IBEA IGD {ibea_igd:.6} is not competitive with NSGA-II IGD {nsga2_igd:.6}"
+    );
 }
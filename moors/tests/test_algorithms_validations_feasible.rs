@@ -19,8 +19,7 @@ fn fitness_binary_biobj(genes: &Array2<f64>) -> Array2<f64> {
 fn constraints_always_infeasible(genes: &Array2<f64>) -> Array1<f64> {
     let n = genes.ncols() as f64;
     let sum = genes.sum_axis(Axis(1));
-    let c = sum.mapv(|s| n - s + 1.0);
-    c
+    sum.mapv(|s| n - s + 1.0)
 }
 
 #[test]
@@ -1,9 +1,11 @@
 // tests/algorithms/test_nsga2_params.rs
 use ndarray::Array2;
+use std::cell::Cell;
+use std::rc::Rc;
 
 use moors::{
-    NoConstraints,
-    algorithms::{AlgorithmBuilderError, Nsga2Builder},
+    NoConstraints, Nsga2RankCrowdingSurvival, RankAndScoringSelectionMOO,
+    algorithms::{AlgorithmBuilder, AlgorithmBuilderError, Nsga2Builder},
     duplicates::NoDuplicatesCleaner,
     impl_constraints_fn,
     operators::{GaussianMutation, RandomSamplingFloat, SimulatedBinaryCrossover},
@@ -87,8 +89,39 @@ fn test_invalid_crossover_rate(#[case] invalid: f64) {
     );
 }
 
+#[rstest]
+#[case(-1e-9)]
+#[case(f64::NAN)]
+#[case(f64::INFINITY)]
+fn test_invalid_dominance_epsilon(#[case] invalid: f64) {
+    let err = match Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(2.0))
+        .mutation(GaussianMutation::new(0.1, 0.05))
+        .duplicates_cleaner(NoDuplicatesCleaner)
+        .fitness_fn(dummy_fitness)
+        .constraints_fn(NoConstraints)
+        .num_vars(10)
+        .population_size(100)
+        .num_offsprings(50)
+        .num_iterations(50)
+        .dominance_epsilon(invalid) // ← invalid here
+        .build()
+    {
+        Ok(_) => panic!("Expected an error for invalid dominance_epsilon"),
+        Err(e) => e,
+    };
+
+    let msg = format!("{}", err);
+    assert!(
+        msg.contains("Dominance epsilon must be a non-negative finite number"),
+        "Unexpected message: {}",
+        msg
+    );
+}
+
 #[test]
-fn test_invalid_n_vars_population_offsprings_iterations() {
+fn test_invalid_n_vars_population_offsprings() {
     // num_vars = 0
     let err = match Nsga2Builder::default()
         .sampler(RandomSamplingFloat::new(0.0, 1.0))
@@ -163,9 +196,49 @@ fn test_invalid_n_vars_population_offsprings_iterations() {
         "Unexpected message: {}",
         err
     );
+}
 
-    // num_iterations = 0
-    let err = match Nsga2Builder::default()
+#[test]
+fn test_zero_iterations_returns_evaluated_initial_population() {
+    let num_calls = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&num_calls);
+    let counting_fitness = move |genes: &Array2<f64>| {
+        counter.set(counter.get() + 1);
+        dummy_fitness(genes)
+    };
+    let mut algorithm = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(2.0))
+        .mutation(GaussianMutation::new(0.1, 0.05))
+        .duplicates_cleaner(NoDuplicatesCleaner)
+        .fitness_fn(counting_fitness)
+        .constraints_fn(NoConstraints)
+        .num_vars(10)
+        .population_size(100)
+        .num_offsprings(50)
+        .num_iterations(0) // ← initialize-and-evaluate only
+        .seed(1)
+        .build()
+        .expect("num_iterations = 0 must be accepted");
+
+    algorithm.run().expect("run with zero iterations failed");
+    let population = algorithm
+        .population()
+        .expect("population should have been initialized");
+
+    // Only the initial sample has been evaluated.
+    assert_eq!(num_calls.get(), 1);
+    assert_eq!(population.len(), 100);
+    assert_eq!(population.fitness, dummy_fitness(&population.genes));
+    assert!(population.genes.iter().all(|&g| (0.0..1.0).contains(&g)));
+    assert!(population.rank.is_some());
+}
+
+#[test]
+fn test_diversity_pressure_requires_single_objective() {
+    let err = match AlgorithmBuilder::default()
+        .selector(RankAndScoringSelectionMOO::default())
+        .survivor(Nsga2RankCrowdingSurvival)
         .sampler(RandomSamplingFloat::new(0.0, 1.0))
         .crossover(SimulatedBinaryCrossover::new(2.0))
         .mutation(GaussianMutation::new(0.1, 0.05))
@@ -175,16 +248,15 @@ fn test_invalid_n_vars_population_offsprings_iterations() {
         .num_vars(10)
         .population_size(100)
         .num_offsprings(50)
-        .num_iterations(0) // ← invalid
-        .mutation_rate(0.1)
-        .crossover_rate(0.9)
+        .num_iterations(50)
+        .diversity_pressure(0.5) // ← only for SOO
         .build()
     {
-        Ok(_) => panic!("Expected error for num_iterations = 0"),
+        Ok(_) => panic!("Expected error for diversity pressure on a multi-objective problem"),
         Err(e) => e,
     };
     assert!(
-        format!("{}", err).contains("Number of iterations must be greater than 0"),
+        format!("{}", err).contains("Diversity pressure is only supported"),
         "Unexpected message: {}",
         err
     );
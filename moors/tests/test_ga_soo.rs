@@ -1,10 +1,12 @@
-use ndarray::{Array1, Array2, Axis};
+use ndarray::{Array1, Array2, Axis, array};
 
 use moors::{
     AlgorithmBuilder, CloseDuplicatesCleaner, GaussianMutation, PopulationSOO, RandomSamplingFloat,
     SimulatedBinaryCrossover, impl_constraints_fn,
     selection::soo::RankSelection,
-    survival::soo::{FitnessConstraintsPenaltySurvival, FitnessSurvival},
+    survival::soo::{
+        AdaptiveConstraintsPenaltySurvival, FitnessConstraintsPenaltySurvival, FitnessSurvival,
+    },
 };
 
 /// Simple minimization of 1 - (x**2 + y**2 + z**2)
@@ -61,7 +63,6 @@ fn test_ga_minimize_parabolid() {
 /// Problem: minimize f(x, y) = x² + y²
 /// Subject to the equality constraint x + y = 1.
 /// The optimal solution is the point on the line closest to the origin, (0.5, 0.5).
-
 fn fitness_quadratic(population: &Array2<f64>) -> Array1<f64> {
     // Compute f = x² + y² for each row [x, y]
     population.map_axis(Axis(1), |row| row.dot(&row))
@@ -156,3 +157,139 @@ fn test_minimize_projection_on_line_constraints_penalty_survival() {
         assert!((gene[1] - 0.5).abs() < 0.01, "y ≈ 0.5, got {}", gene[1]);
     }
 }
+
+#[test]
+fn test_ga_minimize_parabolid_adaptive_penalty_survival() {
+    // The unconstrained optimum lies outside the unit ball, so infeasible individuals
+    // have better fitness. A tiny initial penalty lets them win until it adapts.
+    let mut algorithm = AlgorithmBuilder::default()
+        .sampler(RandomSamplingFloat::new(-2.0, 2.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.05, 0.1))
+        .selector(RankSelection)
+        .survivor(AdaptiveConstraintsPenaltySurvival::new(1e-3, 0.2))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_sphere)
+        .constraints_fn(constraints_sphere)
+        .num_vars(3)
+        .population_size(100)
+        .num_offsprings(50)
+        .num_iterations(150)
+        .mutation_rate(0.1)
+        .crossover_rate(0.9)
+        .keep_infeasible(true)
+        .seed(123)
+        .build()
+        .expect("failed to build GA");
+
+    algorithm.run().expect("GA run failed");
+    let population: PopulationSOO = algorithm
+        .population
+        .expect("population should have been initialized");
+
+    let best = population.best();
+    assert_eq!(best.constraint_violation_totals.unwrap(), array![0.0]);
+    assert!(best.fitness[0].abs() < 1e-2, "got {}", best.fitness[0]);
+}
+
+#[test]
+fn test_ga_best_solution_genes_evaluate_to_fitness() {
+    let mut algorithm = AlgorithmBuilder::default()
+        .sampler(RandomSamplingFloat::new(-2.0, 2.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.05, 0.1))
+        .selector(RankSelection)
+        .survivor(FitnessSurvival)
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_sphere)
+        .constraints_fn(constraints_sphere)
+        .num_vars(3)
+        .population_size(50)
+        .num_offsprings(25)
+        .num_iterations(20)
+        .keep_infeasible(true)
+        .seed(7)
+        .build()
+        .expect("failed to build GA");
+
+    algorithm.run().expect("GA run failed");
+    let population: PopulationSOO = algorithm
+        .population
+        .expect("population should have been initialized");
+
+    let best = population.best_solution();
+    let genes = best.genes.clone().insert_axis(Axis(0));
+    assert_eq!(fitness_sphere(&genes)[0], best.fitness);
+    assert_eq!(
+        best.feasible,
+        constraints_sphere(&genes)[0] <= 0.0,
+        "feasibility must match the constraints of the returned genes"
+    );
+    assert!(
+        population
+            .fitness
+            .iter()
+            .zip(population.constraint_violation_totals.unwrap().iter())
+            .all(|(&f, &cv)| cv > 0.0 || f >= best.fitness)
+    );
+}
+
+/// Deceptive trap: in each variable the slope leads to the local optimum `x = 0`
+/// (value `-1`), while the global optimum is at the opposite bound `x = 1` (value `-2`).
+fn fitness_deceptive_trap(genes: &Array2<f64>) -> Array1<f64> {
+    let trap = |x: f64| {
+        if x < 0.8 {
+            (0.8 - x) / 0.8
+        } else {
+            2.0 * (x - 0.8) / 0.2
+        }
+    };
+    genes.map_axis(Axis(1), |row| -row.iter().map(|&x| trap(x)).sum::<f64>())
+}
+
+impl_constraints_fn!(UnitBounds, lower_bound = 0.0, upper_bound = 1.0);
+
+fn best_fitness_deceptive_trap(diversity_pressure: f64) -> f64 {
+    let mut algorithm = AlgorithmBuilder::default()
+        // Start in the deceptive basin
+        .sampler(RandomSamplingFloat::new(0.0, 0.5))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.1))
+        .selector(RankSelection)
+        .survivor(FitnessSurvival)
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_deceptive_trap)
+        .constraints_fn(UnitBounds)
+        .num_vars(2)
+        .population_size(30)
+        .num_offsprings(30)
+        .num_iterations(200)
+        .mutation_rate(0.5)
+        .diversity_pressure(diversity_pressure)
+        .seed(3)
+        .build()
+        .expect("failed to build GA");
+
+    algorithm.run().expect("GA run failed");
+    algorithm
+        .population
+        .expect("population should have been initialized")
+        .best_solution()
+        .fitness
+}
+
+#[test]
+fn test_ga_diversity_pressure_escapes_deceptive_trap() {
+    // Plain SOO converges to the deceptive optimum (-2 with 2 variables).
+    let plain = best_fitness_deceptive_trap(0.0);
+    assert!(
+        plain > -2.5,
+        "plain SOO unexpectedly escaped the trap: {plain}"
+    );
+    // With diversity pressure the population stays spread and reaches the global optimum (-4).
+    let diverse = best_fitness_deceptive_trap(0.8);
+    assert!(
+        diverse < -3.9,
+        "diversity pressure did not escape the trap: {diverse}"
+    );
+}
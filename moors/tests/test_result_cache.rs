@@ -0,0 +1,156 @@
+#![cfg(feature = "result-cache")]
+use ndarray::{Array2, Axis, stack};
+use std::cell::Cell;
+use std::path::Path;
+use std::rc::Rc;
+
+use moors::{
+    CloseDuplicatesCleaner, GaussianMutation, Nsga2Builder, OptimizationResult,
+    RandomSamplingFloat, SimulatedBinaryCrossover, impl_constraints_fn,
+};
+
+/// Bi-objective fitness:
+/// f₁ = x² + y²
+/// f₂ = (x−1)² + (y−1)²
+fn fitness_biobjective(population_genes: &Array2<f64>) -> Array2<f64> {
+    let x = population_genes.column(0);
+    let y = population_genes.column(1);
+    let f1 = &x * &x + &y * &y;
+    let f2 = (&x - 1.0).mapv(|v| v * v) + (&y - 1.0).mapv(|v| v * v);
+    stack(Axis(1), &[f1.view(), f2.view()]).expect("stack failed")
+}
+
+impl_constraints_fn!(MyConstr, lower_bound = 0.0, upper_bound = 1.0);
+
+/// Runs NSGA-II with the result cache in `dir` under `problem_key`. Returns the result, the
+/// config hash and the number of fitness calls made by this run.
+fn run_cached(dir: &Path, problem_key: &str, seed: u64) -> (OptimizationResult, u64, usize) {
+    let num_calls = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&num_calls);
+    let counting_fitness = move |genes: &Array2<f64>| {
+        counter.set(counter.get() + 1);
+        fitness_biobjective(genes)
+    };
+    let mut algorithm = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(counting_fitness)
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(50)
+        .num_iterations(20)
+        .seed(seed)
+        .rolling_stats(true)
+        .result_cache(dir, problem_key)
+        .build()
+        .expect("failed to build NSGA2");
+    algorithm.run().expect("NSGA2 run failed");
+    let num_generations = algorithm
+        .rolling_stats()
+        .expect("rolling stats are enabled")
+        .num_generations();
+    // A cache hit computes no generation.
+    assert_eq!(num_generations == 0, num_calls.get() == 0);
+    let result = algorithm
+        .optimization_result()
+        .expect("population should have been set");
+    let config_hash = algorithm.config_hash().expect("result cache is enabled");
+    (result, config_hash, num_calls.get())
+}
+
+#[test]
+fn test_result_cache_miss_then_hit() {
+    let dir = std::env::temp_dir().join(format!("moors_result_cache_it_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    // Cache miss: the run computes and stores its result.
+    let (computed, hash, calls) = run_cached(&dir, "biobjective-v1", 42);
+    assert!(calls > 0);
+    assert!(computed.num_evaluations > 0);
+    assert!(dir.join(format!("{hash:016x}.result")).exists());
+
+    // Cache hit: the identical rerun returns the stored result without evaluating.
+    let (cached, cached_hash, calls) = run_cached(&dir, "biobjective-v1", 42);
+    assert_eq!(calls, 0);
+    assert_eq!(cached_hash, hash);
+    assert_eq!(cached.num_evaluations, computed.num_evaluations);
+    assert_eq!(cached.population.genes, computed.population.genes);
+    assert_eq!(cached.population.fitness, computed.population.fitness);
+    assert_eq!(
+        cached.population.constraints,
+        computed.population.constraints
+    );
+    assert_eq!(cached.population.rank, computed.population.rank);
+    assert_eq!(
+        cached.population.survival_score,
+        computed.population.survival_score
+    );
+
+    // A different configuration is a different entry.
+    let (_, other_hash, calls) = run_cached(&dir, "biobjective-v1", 43);
+    assert_ne!(other_hash, hash);
+    assert!(calls > 0);
+
+    // So is a different problem key with the same configuration.
+    let (_, other_hash, calls) = run_cached(&dir, "biobjective-v2", 42);
+    assert_ne!(other_hash, hash);
+    assert!(calls > 0);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_result_cache_requires_seed() {
+    let err = match Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_biobjective)
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(50)
+        .num_iterations(20)
+        .result_cache(std::env::temp_dir(), "biobjective-v1")
+        .build()
+    {
+        Ok(_) => panic!("Expected an error for a result cache without seed"),
+        Err(e) => e,
+    };
+    assert!(
+        format!("{}", err).contains("The result cache requires a seed"),
+        "Unexpected message: {}",
+        err
+    );
+}
+
+#[test]
+fn test_result_cache_requires_problem_key() {
+    let err = match Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_biobjective)
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(50)
+        .num_iterations(20)
+        .seed(42)
+        .result_cache(std::env::temp_dir(), " ")
+        .build()
+    {
+        Ok(_) => panic!("Expected an error for a result cache without problem key"),
+        Err(e) => e,
+    };
+    assert!(
+        format!("{}", err).contains("The result cache requires a non-empty problem key"),
+        "Unexpected message: {}",
+        err
+    );
+}
@@ -1,12 +1,17 @@
-use ndarray::{Array2, Axis, array, stack};
+use ndarray::{Array1, Array2, Axis, array, stack};
 use ordered_float::OrderedFloat;
+use std::cell::RefCell;
 use std::collections::HashSet;
+use std::rc::Rc;
 
 use moors::{
-    AgeMoeaBuilder, ArithmeticCrossover, CloseDuplicatesCleaner, DanAndDenisReferencePoints,
-    GaussianMutation, Nsga2Builder, Nsga3Builder, Nsga3ReferencePointsSurvival, PopulationMOO,
-    RandomSamplingFloat, ReveaBuilder, Rnsga2Builder, SimulatedBinaryCrossover, Spea2Builder,
-    UniformRealMutation, impl_constraints_fn,
+    AgeMoeaBuilder, AlgorithmContextBuilder, AlgorithmError, ArithmeticCrossover, AskTellError,
+    CloseDuplicatesCleaner, DanAndDenisReferencePoints, GaussianMutation, Initialization,
+    MOORandomGenerator, Nsga2Builder, Nsga2RankCrowdingSurvival, Nsga3Builder,
+    Nsga3ReferencePointsSurvival, PopulationMOO, RandomSamplingFloat, ReveaBuilder, Rnsga2Builder,
+    SimulatedBinaryCrossover, Spea2Builder, UniformRealMutation,
+    evaluator::EvaluatorBuilder,
+    impl_constraints_fn,
     survival::moo::{
         Nsga3ReferencePoints, ReveaReferencePointsSurvival, Rnsga2ReferencePointsSurvival,
         StructuredReferencePoints,
@@ -76,7 +81,7 @@ fn test_nsga2() {
     let population = algorithm
         .population()
         .expect("population should have been initialized");
-    assert_small_real_front(&population);
+    assert_small_real_front(population);
 }
 
 #[test]
@@ -104,7 +109,7 @@ fn test_agemoea() {
     let population = algorithm
         .population()
         .expect("population should have been initialized");
-    assert_small_real_front(&population);
+    assert_small_real_front(population);
 }
 
 #[test]
@@ -137,7 +142,7 @@ fn test_nsga3() {
     let population = algorithm
         .population()
         .expect("population should have been initialized");
-    assert_small_real_front(&population);
+    assert_small_real_front(population);
 }
 
 #[test]
@@ -169,7 +174,7 @@ fn test_rnsga2() {
     let population = algorithm
         .population()
         .expect("population should have been initialized");
-    assert_small_real_front(&population);
+    assert_small_real_front(population);
 }
 
 #[test]
@@ -202,7 +207,7 @@ fn test_revea() {
     let population = algorithm
         .population()
         .expect("population should have been initialized");
-    assert_small_real_front(&population);
+    assert_small_real_front(population);
 }
 
 #[test]
@@ -231,7 +236,7 @@ fn test_spea2() {
     let population = algorithm
         .population()
         .expect("population should have been initialized");
-    assert_small_real_front(&population);
+    assert_small_real_front(population);
 }
 
 #[test]
@@ -286,3 +291,519 @@ fn test_same_seed_same_result() {
 
     assert_eq!(population1.genes, population2.genes)
 }
+
+#[test]
+fn test_initial_evaluation_uses_generation_evaluation_path() {
+    // Record the batch size of every fitness call.
+    let calls: Rc<RefCell<Vec<usize>>> = Rc::new(RefCell::new(Vec::new()));
+    let recorder = Rc::clone(&calls);
+    let recording_fitness = move |genes: &Array2<f64>| {
+        recorder.borrow_mut().push(genes.nrows());
+        fitness_biobjective(genes)
+    };
+
+    let mut algorithm = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(recording_fitness)
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(20)
+        .num_iterations(2)
+        .seed(42)
+        .build()
+        .expect("failed to build NSGA2");
+
+    algorithm.run().expect("NSGA2 run failed");
+
+    let calls = calls.borrow();
+    // One evaluation for the initial population plus one per generation.
+    assert_eq!(calls.len(), 3);
+    // The whole initial population is evaluated in a single batch, like every generation.
+    assert_eq!(calls[0], 50);
+    assert!(calls[1..].iter().all(|&n| n > 50 && n <= 70));
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_initial_evaluation_runs_on_multiple_threads() {
+    use moors::ParallelFitness;
+    use std::sync::{Arc, Mutex};
+    use std::thread::{self, ThreadId};
+    use std::time::Duration;
+
+    // Record the thread and the batch size of every fitness call.
+    let calls: Arc<Mutex<Vec<(ThreadId, usize)>>> = Arc::new(Mutex::new(Vec::new()));
+    let recorder = Arc::clone(&calls);
+    let recording_fitness = move |genes: &Array2<f64>| {
+        // Make every chunk slow enough for the idle workers to pick up the others.
+        thread::sleep(Duration::from_millis(20));
+        recorder
+            .lock()
+            .unwrap()
+            .push((thread::current().id(), genes.nrows()));
+        fitness_biobjective(genes)
+    };
+
+    let mut algorithm = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(ParallelFitness::new(recording_fitness).with_chunk_size(5))
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(20)
+        // Only the initial population is evaluated.
+        .num_iterations(0)
+        .seed(42)
+        .build()
+        .expect("failed to build NSGA2");
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .expect("failed to build the thread pool");
+    pool.install(|| algorithm.run()).expect("NSGA2 run failed");
+
+    let calls = calls.lock().unwrap();
+    // The 50 initial individuals are evaluated in 10 chunks of 5 rows.
+    assert_eq!(calls.len(), 10);
+    assert!(calls.iter().all(|&(_, n)| n == 5));
+    let threads: HashSet<ThreadId> = calls.iter().map(|&(id, _)| id).collect();
+    assert!(
+        threads.len() > 1,
+        "the initial evaluation ran on a single thread"
+    );
+    assert_eq!(algorithm.population().unwrap().len(), 50);
+}
+
+#[test]
+fn test_rolling_stats_constant_memory_best_so_far() {
+    let mut algorithm = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_biobjective)
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(50)
+        .num_iterations(100)
+        .rolling_stats(true)
+        .seed(42)
+        .build()
+        .expect("failed to build NSGA2");
+
+    algorithm.initialize().expect("initialization failed");
+    let reference_point = algorithm
+        .rolling_stats()
+        .and_then(|stats| stats.reference_point().cloned())
+        .expect("reference point should be set after initialization");
+
+    let mut expected_best = array![f64::INFINITY, f64::INFINITY];
+    let mut previous_evaluations = 0;
+    for generation in 0..=100 {
+        if generation > 0 {
+            algorithm.next_pop().expect("next_pop failed");
+        }
+        let population = algorithm.population().expect("population should be set");
+        for (k, best) in expected_best.iter_mut().enumerate() {
+            *best = population.fitness.column(k).fold(*best, |a, &b| a.min(b));
+        }
+
+        let stats = algorithm.rolling_stats().expect("rolling stats enabled");
+        assert_eq!(stats.num_generations(), generation);
+        assert_eq!(stats.best_so_far(), Some(&expected_best));
+        assert_eq!(stats.reference_point(), Some(&reference_point));
+        assert!(stats.num_evaluations() > previous_evaluations);
+        assert!(stats.hypervolume() > 0.0);
+        // No history is kept: the storage is bounded by the current population, not by the
+        // number of generations.
+        assert!(stats.num_stored_values() <= (50 + 2) * 2);
+        previous_evaluations = stats.num_evaluations();
+    }
+    // Both objectives reach their minimum (0 at (0, 0) and (1, 1)).
+    let best = algorithm.rolling_stats().unwrap().best_so_far().unwrap();
+    assert!(best.iter().all(|&v| v < 1e-3), "best so far: {best}");
+}
+
+#[test]
+fn test_representative_genes_evaluate_to_objectives() {
+    let mut algorithm = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_biobjective)
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(50)
+        .num_iterations(50)
+        .seed(42)
+        .build()
+        .expect("failed to build NSGA2");
+
+    algorithm.run().expect("NSGA2 run failed");
+    let representative = algorithm
+        .population()
+        .expect("population should have been initialized")
+        .representative();
+
+    let genes = representative.genes.clone().insert_axis(Axis(0));
+    assert_eq!(fitness_biobjective(&genes).row(0), representative.fitness);
+    assert!(representative.feasible);
+    // The knee of this symmetric front lies around (0.5, 0.5).
+    assert!((representative.genes[0] - 0.5).abs() < 0.1);
+    assert!((representative.genes[1] - 0.5).abs() < 0.1);
+}
+
+/// Weight of each individual computed from its genes, strictly positive on `[0, 1]²`.
+fn weight_from_genes(genes: &Array2<f64>) -> Array1<f64> {
+    genes.column(0).mapv(|x| 1.0 + x)
+}
+
+#[test]
+fn test_weights_are_carried_across_generations() {
+    let build = || {
+        Nsga2Builder::default()
+            .sampler(RandomSamplingFloat::new(0.0, 1.0))
+            .crossover(SimulatedBinaryCrossover::new(15.0))
+            .mutation(GaussianMutation::new(0.5, 0.01))
+            .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+            .fitness_fn(fitness_biobjective)
+            .constraints_fn(MyConstr)
+            .num_vars(2)
+            .population_size(50)
+            .num_offsprings(50)
+            .num_iterations(10)
+            .seed(42)
+    };
+
+    // With a weight function every individual, old or new, carries its own weight.
+    let mut algorithm = build()
+        .weight_fn(weight_from_genes)
+        .build()
+        .expect("failed to build NSGA2");
+    algorithm.run().expect("NSGA2 run failed");
+    let population = algorithm
+        .population()
+        .expect("population should have been initialized");
+    assert_eq!(
+        population.weight.as_ref(),
+        Some(&weight_from_genes(&population.genes))
+    );
+
+    // Without one, weights set on the population stay with the surviving parents and the
+    // offspring get uniform weights.
+    let mut algorithm = build().build().expect("failed to build NSGA2");
+    algorithm.initialize().expect("initialization failed");
+    let parents = algorithm.inner.population.as_mut().unwrap();
+    let parent_genes: HashSet<Vec<OrderedFloat<f64>>> = parents
+        .genes
+        .rows()
+        .into_iter()
+        .map(|row| row.iter().map(|&g| OrderedFloat(g)).collect())
+        .collect();
+    parents.set_weight(Array1::from_elem(parents.len(), 2.0));
+    for _ in 0..3 {
+        algorithm.next_pop().expect("next_pop failed");
+    }
+    let population = algorithm
+        .population()
+        .expect("population should have been initialized");
+    let weights = population
+        .weight
+        .as_ref()
+        .expect("weights should be carried");
+    for (row, &weight) in population.genes.rows().into_iter().zip(weights.iter()) {
+        let genes: Vec<OrderedFloat<f64>> = row.iter().map(|&g| OrderedFloat(g)).collect();
+        let expected = if parent_genes.contains(&genes) {
+            2.0
+        } else {
+            1.0
+        };
+        assert_eq!(weight, expected);
+    }
+}
+
+#[test]
+fn test_weight_fn_closure_and_invalid_weights() {
+    use moors::EvaluatorError;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let build = || {
+        Nsga2Builder::default()
+            .sampler(RandomSamplingFloat::new(0.0, 1.0))
+            .crossover(SimulatedBinaryCrossover::new(15.0))
+            .mutation(GaussianMutation::new(0.5, 0.01))
+            .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+            .fitness_fn(fitness_biobjective)
+            .constraints_fn(MyConstr)
+            .num_vars(2)
+            .population_size(50)
+            .num_offsprings(50)
+            .num_iterations(3)
+            .seed(42)
+    };
+
+    // A capturing closure: weights the first (initial) batch by `offset` and NaN afterwards.
+    let offset = 3.0;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let mut algorithm = build()
+        .weight_fn(move |genes: &Array2<f64>| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                Array1::from_elem(genes.nrows(), offset)
+            } else {
+                Array1::from_elem(genes.nrows(), f64::NAN)
+            }
+        })
+        .build()
+        .expect("failed to build NSGA2");
+    algorithm.initialize().expect("initialization failed");
+    let population = algorithm
+        .population()
+        .expect("population should have been initialized");
+    assert_eq!(
+        population.weight,
+        Some(Array1::from_elem(population.len(), offset))
+    );
+    assert!(matches!(
+        algorithm.next_pop(),
+        Err(AlgorithmError::Evaluator(EvaluatorError::InvalidWeights(_)))
+    ));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    // Non-positive weights of the initial population are an error too, not a panic.
+    let mut algorithm = build()
+        .weight_fn(|genes: &Array2<f64>| Array1::zeros(genes.nrows()))
+        .build()
+        .expect("failed to build NSGA2");
+    assert!(matches!(
+        algorithm.run(),
+        Err(AlgorithmError::Evaluator(EvaluatorError::InvalidWeights(_)))
+    ));
+}
+
+/// Mock remote evaluator: jobs are submitted and only completed later, in submission order.
+#[derive(Default)]
+struct MockRemoteEvaluator {
+    queue: Vec<(usize, Array2<f64>)>,
+    max_in_flight: usize,
+}
+
+impl MockRemoteEvaluator {
+    fn submit(&mut self, ticket: usize, genes: Array2<f64>) {
+        self.queue.push((ticket, genes));
+        self.max_in_flight = self.max_in_flight.max(self.queue.len());
+    }
+
+    fn complete_oldest(&mut self) -> (usize, Array2<f64>) {
+        let (ticket, genes) = self.queue.remove(0);
+        (ticket, fitness_biobjective(&genes))
+    }
+}
+
+#[test]
+fn test_pipelined_ask_tell_two_generations_in_flight() {
+    let mut algorithm = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_biobjective)
+        .constraints_fn(MyConstr)
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(50)
+        .num_iterations(100)
+        .pipeline_depth(2)
+        .seed(42)
+        .build()
+        .expect("failed to build NSGA2");
+    algorithm.initialize().expect("initialization failed");
+
+    let mut remote = MockRemoteEvaluator::default();
+    // Fill the pipeline, a third batch does not fit.
+    for _ in 0..2 {
+        let asked = algorithm.ask().expect("ask failed");
+        remote.submit(asked.ticket, asked.genes);
+    }
+    assert_eq!(algorithm.num_in_flight(), 2);
+    assert!(matches!(
+        algorithm.ask(),
+        Err(AlgorithmError::AskTell(AskTellError::PipelineFull(2)))
+    ));
+
+    // Keep the pipeline saturated: each completed batch is replaced by a new one.
+    for _ in 0..100 {
+        let (ticket, fitness) = remote.complete_oldest();
+        algorithm.tell(ticket, fitness).expect("tell failed");
+        let asked = algorithm.ask().expect("ask failed");
+        remote.submit(asked.ticket, asked.genes);
+        assert_eq!(algorithm.num_in_flight(), 2);
+    }
+    while !remote.queue.is_empty() {
+        let (ticket, fitness) = remote.complete_oldest();
+        algorithm.tell(ticket, fitness).expect("tell failed");
+    }
+    assert_eq!(remote.max_in_flight, 2);
+    assert_eq!(algorithm.num_in_flight(), 0);
+    assert!(matches!(
+        algorithm.tell(0, Array2::zeros((50, 2))),
+        Err(AlgorithmError::AskTell(AskTellError::UnknownTicket(0)))
+    ));
+
+    // Despite the staleness the run converges like the synchronous one.
+    let population = algorithm
+        .population()
+        .expect("population should have been initialized");
+    assert_eq!(population.fitness, fitness_biobjective(&population.genes));
+    assert_small_real_front(population);
+}
+
+#[test]
+fn test_tell_all_infeasible_batch_keeps_current_population() {
+    // The constraints become unsatisfiable once the population is initialized.
+    let infeasible = Rc::new(std::cell::Cell::new(false));
+    let flag = Rc::clone(&infeasible);
+    let constraints = move |genes: &Array2<f64>| {
+        let value = if flag.get() { 1.0 } else { -1.0 };
+        Array2::from_elem((genes.nrows(), 1), value)
+    };
+    let mut algorithm = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_biobjective)
+        .constraints_fn(constraints)
+        .keep_infeasible(false)
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(50)
+        .num_iterations(10)
+        .seed(42)
+        .build()
+        .expect("failed to build NSGA2");
+    algorithm.initialize().expect("initialization failed");
+    let before = algorithm
+        .population()
+        .expect("population should have been initialized")
+        .genes
+        .clone();
+
+    let asked = algorithm.ask().expect("ask failed");
+    infeasible.set(true);
+    let fitness = fitness_biobjective(&asked.genes);
+    algorithm
+        .tell(asked.ticket, fitness)
+        .expect("an all-infeasible batch is no new offspring, not an error");
+
+    // The batch was discarded and the survival step ran on the current population only.
+    assert_eq!(algorithm.num_in_flight(), 0);
+    let population = algorithm
+        .population()
+        .expect("population should have been initialized");
+    assert_eq!(population.len(), 50);
+    let rows = |genes: &Array2<f64>| -> HashSet<Vec<OrderedFloat<f64>>> {
+        genes
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().map(|&g| OrderedFloat(g)).collect())
+            .collect()
+    };
+    assert_eq!(rows(&population.genes), rows(&before));
+    assert!(population.rank.is_some());
+}
+
+#[test]
+fn test_tell_advances_iteration_and_reports_evaluator_errors() {
+    use moors::EvaluatorError;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // Valid weights until the flag is set, zero (invalid) weights afterwards.
+    let invalid = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&invalid);
+    let mut algorithm = Nsga2Builder::default()
+        .sampler(RandomSamplingFloat::new(0.0, 1.0))
+        .crossover(SimulatedBinaryCrossover::new(15.0))
+        .mutation(GaussianMutation::new(0.5, 0.01))
+        .duplicates_cleaner(CloseDuplicatesCleaner::new(1e-6))
+        .fitness_fn(fitness_biobjective)
+        .constraints_fn(MyConstr)
+        .weight_fn(move |genes: &Array2<f64>| {
+            let weight = if flag.load(Ordering::SeqCst) {
+                0.0
+            } else {
+                1.0
+            };
+            Array1::from_elem(genes.nrows(), weight)
+        })
+        .num_vars(2)
+        .population_size(50)
+        .num_offsprings(20)
+        .num_iterations(10)
+        .seed(42)
+        .build()
+        .expect("failed to build NSGA2");
+    algorithm.initialize().expect("initialization failed");
+
+    // Every told batch is a generation, numbered like in `run`.
+    for generation in 0..3 {
+        let asked = algorithm.ask().expect("ask failed");
+        let fitness = fitness_biobjective(&asked.genes);
+        algorithm.tell(asked.ticket, fitness).expect("tell failed");
+        assert_eq!(algorithm.inner.context.current_iteration, generation);
+    }
+
+    // Evaluator errors other than an all-infeasible batch are returned.
+    invalid.store(true, Ordering::SeqCst);
+    let asked = algorithm.ask().expect("ask failed");
+    let fitness = fitness_biobjective(&asked.genes);
+    assert!(matches!(
+        algorithm.tell(asked.ticket, fitness),
+        Err(AlgorithmError::Evaluator(EvaluatorError::InvalidWeights(_)))
+    ));
+    assert_eq!(algorithm.inner.context.current_iteration, 2);
+}
+
+#[test]
+fn test_initialization_standalone_returns_population_size_individuals() {
+    let context = AlgorithmContextBuilder::default()
+        .num_vars(2)
+        .population_size(64)
+        .num_offsprings(64)
+        .build()
+        .expect("failed to build context");
+    let mut evaluator = EvaluatorBuilder::default()
+        .fitness(fitness_biobjective)
+        .constraints(MyConstr)
+        .build()
+        .expect("failed to build evaluator");
+    let mut rng = MOORandomGenerator::new_from_seed(Some(42));
+
+    let population = Initialization::initialize(
+        &RandomSamplingFloat::new(0.0, 1.0),
+        &mut Nsga2RankCrowdingSurvival::new(),
+        &mut evaluator,
+        &CloseDuplicatesCleaner::new(1e-6),
+        &mut rng,
+        &context,
+    )
+    .expect("initialization failed");
+
+    assert_eq!(population.len(), context.population_size);
+    assert_eq!(population.fitness, fitness_biobjective(&population.genes));
+    assert!(population.rank.is_some());
+    assert!(population.survival_score.is_some());
+}